@@ -0,0 +1,164 @@
+use aoc_common::parse::{each_line, int_array, non_empty_lines};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+use std::collections::HashMap;
+use std::io;
+
+pub struct Input {
+    pub left: Vec<i32>,
+    pub right: Vec<i32>,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in non_empty_lines(input) {
+        let [first, second] = int_array(input, line)?;
+        left.push(first);
+        right.push(second);
+    }
+
+    Ok(Input { left, right })
+}
+
+/// Like [`parse`], reading one line at a time.
+pub fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>) -> ParseResult<Input> {
+    let pairs = each_line(lines, |line| int_array::<i32, 2>(line, line))?;
+    let (left, right) = pairs.into_iter().map(|[first, second]| (first, second)).unzip();
+
+    Ok(Input { left, right })
+}
+
+/// Summed in i64, since two i32 IDs can lie further apart than an i32 holds.
+pub fn total_distance(left: &mut [i32], right: &mut [i32]) -> i64 {
+    left.sort_unstable();
+    right.sort_unstable();
+
+    left
+        .iter()
+        .zip(right.iter())
+        .map(|(&a, &b)| (i64::from(a) - i64::from(b)).abs())
+        .sum()
+}
+
+/// Summed in i128: each term is an i32 ID times a count of up to the list's length.
+pub fn similarity_score(left: &[i32], right: &[i32]) -> i128 {
+    let mut right_counts = HashMap::new();
+    for &num in right {
+        *right_counts.entry(num).or_insert(0) += 1;
+    }
+
+    left
+        .iter()
+        .map(|&num| i128::from(num) * right_counts.get(&num).copied().unwrap_or(0))
+        .sum()
+}
+
+/// `size` pairs of location IDs; about a third of the right list repeats IDs from the left.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.between(10000, 99999)).collect();
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.chance(1, 3) { *rng.choose(&left) } else { rng.between(10000, 99999) };
+        input.push_str(&format!("{}   {}\n", id, right));
+    }
+
+    input
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let mut left = input.left.clone();
+    let mut right = input.right.clone();
+    total_distance(&mut left, &mut right).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    similarity_score(&input.left, &input.right).into()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+
+    type Input = Input;
+
+    const LINE_ORIENTED: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, _: &Params) -> ParseResult<Input> {
+        parse_lines(lines)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(31));
+    }
+
+    #[test]
+    fn streamed_lines_parse_like_text() {
+        let input = parse_lines(&mut EXAMPLE.lines().map(|line| Ok(line.to_string()))).unwrap();
+        assert_eq!((part1(&input), part2(&input)), (Answer::Integer(11), Answer::Integer(31)));
+
+        let error = parse_lines(&mut ["3   4", "", "4"].into_iter().map(|line| Ok(line.to_string()))).err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (3, "expected 2 numbers, found 1"));
+    }
+
+    #[test]
+    fn malformed_line_reports_position() {
+        let error = parse("3   4\n4   99999999999\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 5, "99999999999"));
+
+        let error = parse("3   4\n4\n").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (2, "expected 2 numbers, found 1"));
+    }
+
+    #[test]
+    fn extreme_ids_do_not_overflow() {
+        let input = parse("2147483647   -2147483648\n2147483647   2147483647\n").unwrap();
+        assert_eq!(part1(&input), Answer::Integer(4294967295));
+        assert_eq!(part2(&input), Answer::Integer(2 * 2147483647));
+    }
+
+    #[test]
+    fn non_numeric_tokens_are_errors() {
+        let error = parse("3 foo 4\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "foo"));
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::grid::{Grid, Position};
+use aoc_common::rng::Rng;
+use aoc_common::search::{bfs, Search};
+use aoc_common::{Answer, ParseResult, Solution};
+
+pub type Input = Grid<u8>;
+
+/// Heights are digits; `.` marks impassable tiles.
+pub fn parse(input: &str) -> ParseResult<Input> {
+    Grid::parse_with(input, |c| match c {
+        '.' => Some(u8::MAX),
+        _ => c.to_digit(10).map(|d| d as u8),
+    })
+}
+
+/// Explores every uphill trail from `start`; each step climbs exactly one level.
+pub fn explore_trails(map: &Grid<u8>, start: Position) -> Search<Position> {
+    let uphill = |&position: &Position| {
+        map.neighbors4(position)
+            .filter(move |&next| map[next] == map[position] + 1)
+            .map(|next| (next, 1))
+    };
+
+    bfs(&uphill, start, |_| false)
+}
+
+pub fn sum_trailhead_scores(map: &Grid<u8>) -> usize {
+    map.find_all(|&height| height == 0)
+        .map(|start| trailhead_score(map, start))
+        .sum()
+}
+
+pub fn trailhead_score(map: &Grid<u8>, start: Position) -> usize {
+    explore_trails(map, start)
+        .reached()
+        .filter(|&&position| map[position] == 9)
+        .count()
+}
+
+pub fn sum_trailhead_ratings(map: &Grid<u8>) -> usize {
+    map.find_all(|&height| height == 0)
+        .map(|start| trailhead_rating(map, start))
+        .sum()
+}
+
+pub fn trailhead_rating(map: &Grid<u8>, start: Position) -> usize {
+    let trails = explore_trails(map, start);
+
+    map.find_all(|&height| height == 9)
+        .map(|end| trails.path_count(end))
+        .sum()
+}
+
+/// A `size`×`size` map whose heights mostly climb by one towards the bottom right,
+/// so there are plenty of trails.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let heights = (0..size * size)
+        .map(|index| {
+            let slope = (index / size + index % size) as i64;
+            let height = if rng.chance(4, 5) { slope } else { rng.between(0, 9) };
+            char::from_digit((height % 10) as u32, 10).unwrap()
+        })
+        .collect();
+
+    format!("{}\n", Grid::from_vec(size, heights))
+}
+
+pub fn part1(map: &Input) -> Answer {
+    sum_trailhead_scores(map).into()
+}
+
+pub fn part2(map: &Input) -> Answer {
+    sum_trailhead_ratings(map).into()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 10;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(36));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(81));
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_common::parse::ints;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+use std::collections::HashMap;
+
+pub struct Input {
+    pub stones: Vec<u64>,
+    pub part1_blinks: usize,
+    pub part2_blinks: usize,
+}
+
+pub fn split_number(n: u128) -> (u128, u128) {
+    let digits = n.to_string();
+    let mid = digits.len() / 2;
+    let left: u128 = digits[..mid].parse().unwrap_or(0);
+    let right: u128 = digits[mid..].parse().unwrap_or(0);
+    (left, right)
+}
+
+/// Stones are tracked as u128s: multiplying a large engraving by 2024 can outgrow a
+/// u64 for a blink or two before it splits, but never by more than a few digits.
+pub fn simulate(initial_stones: &[u64], blinks: usize) -> usize {
+    let mut current_state: HashMap<u128, usize> = HashMap::new();
+
+    for &stone in initial_stones {
+        *current_state.entry(stone.into()).or_insert(0) += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next_state: HashMap<u128, usize> = HashMap::new();
+
+        for (&stone, &count) in &current_state {
+            match stone {
+                0 => {
+                    *next_state.entry(1).or_insert(0) += count;
+                }
+                _ if stone.to_string().len() % 2 == 0 => {
+                    let (left, right) = split_number(stone);
+                    *next_state.entry(left).or_insert(0) += count;
+                    *next_state.entry(right).or_insert(0) += count;
+                }
+                _ => {
+                    *next_state.entry(stone * 2024).or_insert(0) += count;
+                }
+            }
+        }
+
+        current_state = next_state;
+    }
+
+    current_state.values().sum()
+}
+
+/// Blinks by rewriting the whole row of stones, exactly as the puzzle describes.
+pub fn simulate_row(initial_stones: &[u64], blinks: usize) -> usize {
+    let mut stones: Vec<u128> = initial_stones.iter().map(|&stone| stone.into()).collect();
+
+    for _ in 0..blinks {
+        stones = stones
+            .into_iter()
+            .flat_map(|stone| match stone {
+                0 => vec![1],
+                _ if stone.to_string().len() % 2 == 0 => {
+                    let (left, right) = split_number(stone);
+                    vec![left, right]
+                }
+                _ => vec![stone * 2024],
+            })
+            .collect();
+    }
+
+    stones.len()
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    parse_with(input, &Params::default())
+}
+
+pub fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+    Ok(Input {
+        stones: ints(input, input)?,
+        part1_blinks: params.get("part1_blinks", 25)?,
+        part2_blinks: params.get("part2_blinks", 75)?,
+    })
+}
+
+/// `size` stones engraved with numbers below ten million.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size).map(|_| rng.between(0, 9_999_999).to_string()).collect();
+
+    format!("{}\n", stones.join(" "))
+}
+
+pub fn part1(input: &Input) -> Answer {
+    simulate(&input.stones, input.part1_blinks).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    simulate(&input.stones, input.part2_blinks).into()
+}
+
+pub fn reference(input: &Input, part: u8) -> Option<Answer> {
+    (part == 1).then(|| simulate_row(&input.stones, input.part1_blinks).into())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 11;
+
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["part1_blinks", "part2_blinks"];
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+        parse_with(input, params)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn reference(input: &Input, part: u8) -> Option<Answer> {
+        reference(input, part)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "125 17\n";
+
+    #[test]
+    fn blink_example() {
+        assert_eq!(simulate(&parse(EXAMPLE).unwrap().stones, 6), 22);
+    }
+
+    #[test]
+    fn blink_counts_come_from_params() {
+        let params = Params::new().with("part1_blinks", "6").with("part2_blinks", "25");
+        let input = parse_with(EXAMPLE, &params).unwrap();
+
+        assert_eq!(part1(&input), Answer::Integer(22));
+        assert_eq!(part2(&input), Answer::Integer(55312));
+    }
+
+    #[test]
+    fn engravings_can_outgrow_a_u64() {
+        let input = parse("9604294967296 18446744073709551615\n").unwrap();
+        assert_eq!(simulate(&input.stones, 3), simulate_row(&input.stones, 3));
+        assert_eq!(simulate(&input.stones, 3), 6);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(55312));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(65601038650482));
+    }
+
+    #[test]
+    fn non_numeric_stones_are_errors() {
+        let error = parse("125 17x\n").err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (5, "17x"));
+    }
+}
@@ -0,0 +1,219 @@
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseResult, Solution};
+use std::collections::{HashSet, VecDeque};
+
+pub type Region = (char, usize, Vec<Point>);
+
+pub type Input = Grid<char>;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    Grid::parse(input)
+}
+
+pub fn find_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut regions = Vec::new();
+
+    for start in grid.positions() {
+        if !visited[start] {
+            let plant = grid[start];
+            let mut stack = vec![start];
+            let mut cells = Vec::new();
+            let mut perimeter = 0;
+
+            while let Some(current) = stack.pop() {
+                if visited[current] {
+                    continue;
+                }
+                visited[current] = true;
+                cells.push(Point::from_position(current));
+
+                for direction in Direction::ALL {
+                    match grid.step(current, direction) {
+                        Some(next) if grid[next] == plant => {
+                            if !visited[next] {
+                                stack.push(next);
+                            }
+                        }
+                        _ => perimeter += 1,
+                    }
+                }
+            }
+
+            regions.push((plant, perimeter, cells));
+        }
+    }
+
+    regions
+}
+
+pub fn count_region_sides(region: &[Point]) -> usize {
+    let region_set: HashSet<_> = region.iter().copied().collect();
+    let mut side_count = 0;
+
+    for direction in Direction::ALL {
+        let sides: HashSet<Point> = region
+            .iter()
+            .map(|&cell| cell.step(direction))
+            .filter(|neighbor| !region_set.contains(neighbor))
+            .collect();
+
+        let along_side = direction.turn_right();
+        let mut neighbors_to_remove = HashSet::new();
+        for &side in &sides {
+            let mut neighbor = side.step(along_side);
+            while sides.contains(&neighbor) {
+                neighbors_to_remove.insert(neighbor);
+                neighbor = neighbor.step(along_side);
+            }
+        }
+
+        side_count += sides.len() - neighbors_to_remove.len();
+    }
+
+    side_count
+}
+
+pub fn calculate_total_cost<F>(regions: &[Region], cost_fn: F) -> usize
+where
+    F: Fn(&Region) -> usize,
+{
+    regions.iter().map(cost_fn).sum()
+}
+
+pub fn region_cost_part_1(region: &Region) -> usize {
+    let (_, perimeter, cells) = region;
+    cells.len() * perimeter
+}
+
+pub fn region_cost_part_2(region: &Region) -> usize {
+    let (_, _, cells) = region;
+    count_region_sides(cells) * cells.len()
+}
+
+/// A `size`×`size` garden whose regions grow outwards from about one seed plot in 25.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = Grid::new(size, size, None);
+    let mut queue = VecDeque::new();
+    for _ in 0..(size * size / 25).max(1) {
+        let position = (rng.index(size), rng.index(size));
+        if garden[position].is_none() {
+            garden[position] = Some(*rng.choose(&['A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M']));
+            queue.push_back(position);
+        }
+    }
+
+    while let Some(position) = queue.pop_front() {
+        let neighbors: Vec<_> = garden.neighbors4(position).collect();
+        for next in neighbors {
+            if garden[next].is_none() {
+                garden[next] = garden[position];
+                queue.push_back(next);
+            }
+        }
+    }
+
+    format!("{}\n", garden.map(|plant| plant.expect("every plot is reached from a seed")))
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    calculate_total_cost(&find_regions(grid), region_cost_part_1).into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    calculate_total_cost(&find_regions(grid), region_cost_part_2).into()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 12;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const NESTED_EXAMPLE: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    const LARGE_EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const E_SHAPED_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const DIAGONAL_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&parse(SMALL_EXAMPLE).unwrap()), Answer::Integer(140));
+        assert_eq!(part1(&parse(NESTED_EXAMPLE).unwrap()), Answer::Integer(772));
+        assert_eq!(part1(&parse(LARGE_EXAMPLE).unwrap()), Answer::Integer(1930));
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2(&parse(SMALL_EXAMPLE).unwrap()), Answer::Integer(80));
+        assert_eq!(part2(&parse(NESTED_EXAMPLE).unwrap()), Answer::Integer(436));
+        assert_eq!(part2(&parse(E_SHAPED_EXAMPLE).unwrap()), Answer::Integer(236));
+        assert_eq!(part2(&parse(DIAGONAL_EXAMPLE).unwrap()), Answer::Integer(368));
+        assert_eq!(part2(&parse(LARGE_EXAMPLE).unwrap()), Answer::Integer(1206));
+    }
+}
@@ -0,0 +1,247 @@
+use aoc_common::parse::{blocks, int_pattern};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+
+#[derive(Clone, Copy)]
+pub struct Machine { pub ax: i64, pub ay: i64, pub bx: i64, pub by: i64, pub px: i64, pub py: i64 }
+
+impl Machine {
+    /// `[ax, ay, bx, by, px, py]` as i128s, in which products of two of them, and
+    /// differences of such products, cannot overflow.
+    fn widened(&self) -> [i128; 6] {
+        [self.ax, self.ay, self.bx, self.by, self.px, self.py].map(i128::from)
+    }
+}
+
+pub type Input = Vec<Machine>;
+
+pub const PRIZE_OFFSET: i64 = 10000000000000;
+/// Part 1 presses neither button more than this many times.
+pub const PRESS_LIMIT: i64 = 100;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    blocks(input)
+        .map(|block| {
+            let lines: Vec<&str> = block.lines().collect();
+            let [a_line, b_line, p_line] = lines[..] else {
+                return Err(ParseError::at(
+                    input,
+                    block,
+                    format!("expected a machine of 3 lines, found {}", lines.len()),
+                ));
+            };
+
+            let [ax, ay] = int_pattern(input, a_line, "Button A: X+{}, Y+{}")?;
+            let [bx, by] = int_pattern(input, b_line, "Button B: X+{}, Y+{}")?;
+            let [px, py] = int_pattern(input, p_line, "Prize: X={}, Y={}")?;
+
+            Ok(Machine { ax, ay, bx, by, px, py })
+        })
+        .collect()
+}
+
+pub fn solve_linear_equations(machine: &Machine) -> Option<(i64, i64)> {
+    let [ax, ay, bx, by, px, py] = machine.widened();
+    let det = ax * by - ay * bx;
+
+    if det == 0 {
+        return None;
+    }
+
+    let det_x = px * by - py * bx;
+    let det_y = ax * py - ay * px;
+
+    if det_x % det == 0 && det_y % det == 0 {
+        let x = i64::try_from(det_x / det).ok()?;
+        let y = i64::try_from(det_y / det).ok()?;
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+/// Button presses for every machine whose prize can be won once moved by `prize_offset`,
+/// pressing each button between 0 and `press_limit` times.
+pub fn winning_presses(
+    machines: &[Machine],
+    prize_offset: i64,
+    press_limit: i64,
+) -> impl Iterator<Item = (i64, i64)> + '_ {
+    machines.iter().filter_map(move |machine| {
+        let (px, py) = (machine.px.checked_add(prize_offset)?, machine.py.checked_add(prize_offset)?);
+        solve_linear_equations(&Machine { px, py, ..*machine })
+            .filter(|&(a, b)| (0..=press_limit).contains(&a) && (0..=press_limit).contains(&b))
+    })
+}
+
+pub fn total_tokens(machines: &[Machine], prize_offset: i64, press_limit: i64) -> i128 {
+    winning_presses(machines, prize_offset, press_limit).map(|(a, b)| 3 * i128::from(a) + i128::from(b)).sum()
+}
+
+/// The cheapest win found by trying every pair of press counts up to the limit.
+pub fn cheapest_by_search(machine: &Machine) -> Option<i128> {
+    let [ax, ay, bx, by, px, py] = machine.widened();
+    (0..=i128::from(PRESS_LIMIT))
+        .flat_map(|a| (0..=i128::from(PRESS_LIMIT)).map(move |b| (a, b)))
+        .filter(|&(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+        .map(|(a, b)| 3 * a + b)
+        .min()
+}
+
+/// `size` claw machines; about half of them can be won.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let machines: Vec<String> = (0..size)
+        .map(|_| loop {
+            let [ax, ay, bx, by] = [(); 4].map(|_| rng.between(10, 99));
+            if ax * by == ay * bx {
+                continue;
+            }
+
+            let (a, b) = (rng.between(0, 100), rng.between(0, 100));
+            let (mut px, py) = (a * ax + b * bx, a * ay + b * by);
+            if rng.chance(1, 2) {
+                px += rng.between(1, 9);
+            }
+
+            break format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", ax, ay, bx, by, px, py);
+        })
+        .collect();
+
+    machines.join("\n")
+}
+
+pub fn part1(machines: &Input) -> Answer {
+    total_tokens(machines, 0, PRESS_LIMIT).into()
+}
+
+pub fn part2(machines: &Input) -> Answer {
+    total_tokens(machines, PRIZE_OFFSET, i64::MAX).into()
+}
+
+pub fn reference(machines: &Input, part: u8) -> Option<Answer> {
+    (part == 1).then(|| machines.iter().filter_map(cheapest_by_search).sum::<i128>().into())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 13;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn reference(input: &Input, part: u8) -> Option<Answer> {
+        reference(input, part)
+    }
+
+    fn diagnostics(input: &Input) -> Vec<(&'static str, Answer)> {
+        vec![
+            ("part1_prizes_won", winning_presses(input, 0, PRESS_LIMIT).count().into()),
+            ("part2_prizes_won", winning_presses(input, PRIZE_OFFSET, i64::MAX).count().into()),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(480));
+    }
+
+    #[test]
+    fn part2_example() {
+        let winnable: Vec<bool> = parse(EXAMPLE).unwrap()
+            .iter()
+            .map(|machine| {
+                let adjusted = Machine { px: machine.px + PRIZE_OFFSET, py: machine.py + PRIZE_OFFSET, ..*machine };
+                solve_linear_equations(&adjusted).is_some()
+            })
+            .collect();
+
+        assert_eq!(winnable, [false, true, false, true]);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(875318608908));
+    }
+
+    #[test]
+    fn presses_must_be_within_limits() {
+        let machines = parse("Button A: X+69, Y+88\nButton B: X+51, Y+64\nPrize: X=5166, Y=48\n").unwrap();
+        assert_eq!(solve_linear_equations(&machines[0]), Some((-4558, 6268)));
+        assert_eq!(part1(&machines), Answer::Integer(0));
+
+        let machines = parse("Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=150, Y=2\n").unwrap();
+        assert_eq!(part1(&machines), Answer::Integer(0));
+        assert_eq!(reference(&machines, 1), Some(Answer::Integer(0)));
+    }
+
+    #[test]
+    fn huge_numbers_do_not_overflow() {
+        let machines = parse(
+            "Button A: X+1, Y+9\nButton B: X+7483648, Y+5\nPrize: X=2, Y=7\n\n\
+             Button A: X+6, Y+7\nButton B: X+6, Y+8\nPrize: X=6, Y=3\n",
+        )
+        .unwrap();
+        assert_eq!((part1(&machines), part2(&machines)), (Answer::Integer(0), Answer::Integer(0)));
+
+        let machine = "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=9223372036854775807, Y=9223372036854775807\n";
+        let machines = parse(machine).unwrap();
+        assert_eq!(part1(&machines), Answer::Integer(0));
+        assert_eq!(part2(&machines), Answer::Integer(0));
+        assert_eq!(total_tokens(&machines, 0, i64::MAX), 4 * i128::from(i64::MAX));
+    }
+
+    #[test]
+    fn reports_prizes_won() {
+        let diagnostics = Day13::diagnostics(&parse(EXAMPLE).unwrap());
+        assert_eq!(diagnostics, [("part1_prizes_won", Answer::Integer(2)), ("part2_prizes_won", Answer::Integer(2))]);
+    }
+
+    #[test]
+    fn malformed_machine_reports_position() {
+        let error = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected a machine of 3 lines, found 2");
+
+        let error = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=54o0\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 18, "54o0"));
+
+        let error = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Z=5400\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 14, ", Z=5400"));
+    }
+}
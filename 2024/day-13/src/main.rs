@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::solution::run_main::<y2024_day_13::Day13>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,268 @@
+use aoc_common::frame::{Cell, Color, Frame};
+use aoc_common::geometry::Point;
+use aoc_common::grid::Grid;
+use aoc_common::parse::{each_line, int_pattern, non_empty_lines};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+pub type Robot = ((isize, isize), (isize, isize));
+
+pub struct Input {
+    pub robots: Vec<Robot>,
+    pub width: usize,
+    pub height: usize,
+    /// Seconds simulated before the safety factor is taken.
+    pub seconds: usize,
+}
+
+pub const WIDTH: usize = 101;
+pub const HEIGHT: usize = 103;
+pub const SECONDS: usize = 100;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    parse_with(input, &Params::default())
+}
+
+pub fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+    let robots = non_empty_lines(input).map(|line| parse_robot(input, line)).collect::<ParseResult<_>>()?;
+    with_robots(robots, params)
+}
+
+/// Like [`parse_with`], reading one robot at a time.
+pub fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, params: &Params) -> ParseResult<Input> {
+    with_robots(each_line(lines, |line| parse_robot(line, line))?, params)
+}
+
+fn parse_robot(input: &str, line: &str) -> ParseResult<Robot> {
+    int_pattern(input, line, "p={},{} v={},{}").map(|[px, py, vx, vy]| ((px, py), (vx, vy)))
+}
+
+fn with_robots(robots: Vec<Robot>, params: &Params) -> ParseResult<Input> {
+    Ok(Input {
+        robots,
+        width: params.get_positive("width", WIDTH)?,
+        height: params.get_positive("height", HEIGHT)?,
+        seconds: params.get("seconds", SECONDS)?,
+    })
+}
+
+/// The tile `robot` is on after `seconds`. Reducing everything modulo the room's
+/// size first keeps the arithmetic small, whatever numbers the input holds.
+pub fn position_after(&((px, py), (vx, vy)): &Robot, width: usize, height: usize, seconds: usize) -> (usize, usize) {
+    let axis = |position: isize, velocity: isize, size: usize| {
+        let time = (seconds % size) as isize;
+        let size = size as isize;
+        (position.rem_euclid(size) + velocity.rem_euclid(size) * time).rem_euclid(size) as usize
+    };
+
+    (axis(px, vx, width), axis(py, vy, height))
+}
+
+/// Robots in the top left, top right, bottom left and bottom right quadrants after
+/// `seconds`; robots on the middle row or column count for none.
+pub fn quadrant_counts(robots: &[Robot], width: usize, height: usize, seconds: usize) -> [usize; 4] {
+    let final_positions = robots.iter().map(|robot| position_after(robot, width, height, seconds));
+
+    let mut tile_counts = HashMap::new();
+    for position in final_positions {
+        *tile_counts.entry(position).or_insert(0) += 1;
+    }
+
+    let mut quadrants = [0, 0, 0, 0];
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    for ((x, y), count) in tile_counts {
+        if x == mid_x || y == mid_y {
+            continue;
+        }
+
+        if x < mid_x && y < mid_y {
+            quadrants[0] += count;
+        } else if x >= mid_x && y < mid_y {
+            quadrants[1] += count;
+        } else if x < mid_x && y >= mid_y {
+            quadrants[2] += count;
+        } else {
+            quadrants[3] += count;
+        }
+    }
+
+    quadrants
+}
+
+pub fn calculate_safety_factor(robots: &[Robot], width: usize, height: usize, seconds: usize) -> usize {
+    quadrant_counts(robots, width, height, seconds).iter().product()
+}
+
+pub fn find_easter_egg(robots: &[Robot], width: usize, height: usize) -> Option<usize> {
+    (0..width * height).find(|&t| {
+        let mut occupied = HashSet::new();
+        robots.iter().all(|robot| occupied.insert(position_after(robot, width, height, t)))
+    })
+}
+
+/// The robots at `seconds`, drawn like the puzzle: a count per tile, `.` for none.
+pub fn robots_frame(robots: &[Robot], width: usize, height: usize, seconds: usize) -> Frame {
+    let mut counts = Grid::new(width, height, 0);
+    for robot in robots {
+        let (x, y) = position_after(robot, width, height, seconds);
+        counts[(y, x)] += 1;
+    }
+
+    Frame::from_grid(&counts, |&count| match count {
+        0 => Cell::new('.', Color::DARK_GRAY),
+        1 => Cell::new('1', Color::GREEN),
+        _ => Cell::new(char::from_digit(count.min(9), 10).unwrap_or('+'), Color::YELLOW),
+    })
+}
+
+/// One frame per second until the robots form the Easter egg, or for a whole
+/// cycle when they never do.
+pub fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+    let last = find_easter_egg(&input.robots, input.width, input.height)
+        .unwrap_or((input.width * input.height).saturating_sub(1));
+
+    for seconds in 0..=last {
+        let frame = robots_frame(&input.robots, input.width, input.height, seconds);
+        emit(frame.label(Point::ORIGIN, format!("{}s", seconds), Color::WHITE));
+    }
+}
+
+/// `size` robots on the real puzzle's 101×103 floor.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let (px, py) = (rng.index(WIDTH), rng.index(HEIGHT));
+        let (vx, vy) = (rng.between(-99, 99), rng.between(-99, 99));
+        input.push_str(&format!("p={},{} v={},{}\n", px, py, vx, vy));
+    }
+
+    input
+}
+
+pub fn part1(input: &Input) -> Answer {
+    calculate_safety_factor(&input.robots, input.width, input.height, input.seconds).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    find_easter_egg(&input.robots, input.width, input.height).into()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 14;
+
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["width", "height", "seconds"];
+
+    const LINE_ORIENTED: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+        parse_with(input, params)
+    }
+
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, params: &Params) -> ParseResult<Input> {
+        parse_lines(lines, params)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn diagnostics(input: &Input) -> Vec<(&'static str, Answer)> {
+        let quadrants = quadrant_counts(&input.robots, input.width, input.height, input.seconds);
+        vec![("quadrants", quadrants.iter().map(|&count| count as i64).collect::<Vec<_>>().into())]
+    }
+
+    fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+        visualize(input, emit)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(quadrant_counts(&parse(EXAMPLE).unwrap().robots, 11, 7, 100), [1, 3, 4, 1]);
+        assert_eq!(calculate_safety_factor(&parse(EXAMPLE).unwrap().robots, 11, 7, 100), 12);
+
+        let params = Params::new().with("width", "11").with("height", "7");
+        assert_eq!(part1(&parse_with(EXAMPLE, &params).unwrap()), Answer::Integer(12));
+    }
+
+    #[test]
+    fn frames_draw_robot_counts_like_the_puzzle() {
+        let frame = robots_frame(&parse(EXAMPLE).unwrap().robots, 11, 7, 100);
+        let rows: Vec<String> = frame.cells.rows().map(|row| row.iter().map(|cell| cell.glyph).collect()).collect();
+
+        assert_eq!(
+            rows,
+            ["......2..1.", "...........", "1..........", ".11........", ".....1.....", "...12......", ".1....1...."]
+        );
+    }
+
+    #[test]
+    fn part2_finds_first_frame_without_overlaps() {
+        let robots = [((0, 0), (0, 0)), ((0, 0), (1, 0)), ((3, 3), (0, 0))];
+
+        assert_eq!(find_easter_egg(&robots, 11, 7), Some(1));
+        assert_eq!(find_easter_egg(&[((0, 0), (0, 0)), ((0, 0), (0, 0))], 11, 7), None);
+    }
+
+    #[test]
+    fn huge_positions_and_velocities_wrap_without_overflow() {
+        let input = parse("p=9,1 v=9223372036854775807,-9\n").unwrap();
+        assert_eq!(position_after(&input.robots[0], 11, 7, 100), (5, 4));
+        assert_eq!(part1(&input), Answer::Integer(0));
+    }
+
+    #[test]
+    fn rooms_must_have_a_size() {
+        let error = parse_with(EXAMPLE, &Params::new().with("width", "0")).err().unwrap();
+        assert_eq!(error.to_string(), "parameter `width`: must be at least 1: `0`");
+        assert!(parse_with(EXAMPLE, &Params::new().with("height", "0")).is_err());
+    }
+
+    #[test]
+    fn robots_must_match_the_format() {
+        let error = parse("p=0,4 v=3,-3\np=6,3 x v=-1,-3\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 6, " x v=-1,-3"));
+        let error = parse("p=0,4 v=3,-3z\n").err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (11, "-3z"));
+    }
+}
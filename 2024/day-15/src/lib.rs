@@ -0,0 +1,339 @@
+use aoc_common::frame::{Cell, Color, Frame};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::parse::block_array;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use std::collections::VecDeque;
+
+pub struct Input {
+    pub warehouse_map: Grid<char>,
+    pub moves: Vec<Direction>,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let [map_text, moves] = block_array(input)?;
+    let warehouse_map = Grid::parse_with(map_text, |c| matches!(c, '#' | '.' | 'O' | '@').then_some(c))?;
+    if warehouse_map.find(&'@').is_none() {
+        return Err(ParseError::at_end(map_text, "no robot (@) in the warehouse"));
+    }
+    // Moves never check the map's bounds; the walls around it stop them.
+    let (height, width) = (warehouse_map.height(), warehouse_map.width());
+    let gap = warehouse_map
+        .iter()
+        .find(|&((row, col), &tile)| (row == 0 || col == 0 || row == height - 1 || col == width - 1) && tile != '#');
+    if let Some(((row, col), _)) = gap {
+        let line = map_text.lines().filter(|line| !line.is_empty()).nth(row).unwrap_or_default();
+        return Err(ParseError::at(input, &line[col..col + 1], "the warehouse must be surrounded by walls"));
+    }
+
+    let moves = moves
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(offset, c)| {
+            Direction::from_char(c).ok_or_else(|| {
+                ParseError::at(input, &moves[offset..offset + c.len_utf8()], "invalid movement command")
+            })
+        })
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Input { warehouse_map, moves })
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum TileType {
+    Empty,
+    Wall,
+    BoxLeft,
+    BoxRight,
+}
+
+pub fn parse_tile_type(tile_char: char) -> TileType {
+    match tile_char {
+        '.' | '@' => TileType::Empty,
+        '#' => TileType::Wall,
+        'O' => TileType::BoxLeft,
+        _ => panic!("Invalid tile character"),
+    }
+}
+
+pub fn parse_tile_type_scaled(tile_char: char) -> [TileType; 2] {
+    match tile_char {
+        '.' | '@' => [TileType::Empty; 2],
+        '#' => [TileType::Wall; 2],
+        'O' => [TileType::BoxLeft, TileType::BoxRight],
+        _ => panic!("Invalid tile character"),
+    }
+}
+
+pub fn calculate_box_gps_coordinate(position: Point) -> usize {
+    (position.x + position.y * 100) as usize
+}
+
+pub fn create_map(warehouse_map: &Grid<char>, scale: usize) -> (Grid<TileType>, Point) {
+    let robot = Point::from_position(warehouse_map.find(&'@').expect("No robot in warehouse"));
+    let robot_position = Point::new(robot.x * scale as i64, robot.y);
+
+    let tiles: Vec<TileType> = if scale == 1 {
+        warehouse_map.cells().iter().map(|&c| parse_tile_type(c)).collect()
+    } else {
+        warehouse_map.cells().iter().flat_map(|&c| parse_tile_type_scaled(c)).collect()
+    };
+
+    (Grid::from_vec(warehouse_map.width() * scale, tiles), robot_position)
+}
+
+pub fn execute_moves_part_1(map: &mut Grid<TileType>, robot_position: &mut Point, moves: &[Direction]) {
+    for &direction in moves {
+        let mut new_robot_position = *robot_position;
+        loop {
+            new_robot_position = new_robot_position.step(direction);
+            match map[new_robot_position] {
+                TileType::Empty => {
+                    *robot_position = robot_position.step(direction);
+                    map[new_robot_position] = map[*robot_position];
+                    map[*robot_position] = TileType::Empty;
+                    break;
+                }
+                TileType::Wall => break,
+                TileType::BoxLeft => {}
+                TileType::BoxRight => panic!("Invalid box configuration"),
+            }
+        }
+    }
+}
+
+pub fn collect_box_coordinates(map: &Grid<TileType>) -> usize {
+    map.find_all(|&tile| tile == TileType::BoxLeft)
+        .map(|position| calculate_box_gps_coordinate(Point::from_position(position)))
+        .sum()
+}
+
+pub fn sum_box_coordinates(input: &Input) -> usize {
+    let (mut map, mut robot_position) = create_map(&input.warehouse_map, 1);
+    execute_moves_part_1(&mut map, &mut robot_position, &input.moves);
+
+    collect_box_coordinates(&map)
+}
+
+pub fn execute_moves_part_2(map: &mut Grid<TileType>, robot_position: &mut Point, moves: &[Direction]) {
+    let mut boxes_to_move = Vec::new();
+    let mut positions_to_check = VecDeque::new();
+
+    for &direction in moves {
+        let mut is_movement_blocked = false;
+        positions_to_check.push_back(robot_position.step(direction));
+
+        while let Some(position_to_check) = positions_to_check.pop_front() {
+            if let Some(box_start_position) = match map[position_to_check] {
+                TileType::Empty => None,
+                TileType::Wall => {
+                    is_movement_blocked = true;
+                    break;
+                }
+                TileType::BoxLeft => Some(position_to_check),
+                TileType::BoxRight => Some(position_to_check.step(Direction::Left)),
+            } {
+                if !boxes_to_move.contains(&box_start_position) {
+                    boxes_to_move.push(box_start_position);
+                    if direction != Direction::Right {
+                        positions_to_check.push_back(box_start_position.step(direction));
+                    }
+                    if direction != Direction::Left {
+                        positions_to_check.push_back(box_start_position.step(Direction::Right).step(direction));
+                    }
+                }
+            }
+        }
+
+        if !is_movement_blocked {
+            *robot_position = robot_position.step(direction);
+            for &box_start_position in boxes_to_move.iter().rev() {
+                let box_end_position = box_start_position.step(Direction::Right);
+                map[box_start_position] = TileType::Empty;
+                map[box_end_position] = TileType::Empty;
+                map[box_start_position.step(direction)] = TileType::BoxLeft;
+                map[box_end_position.step(direction)] = TileType::BoxRight;
+            }
+        }
+
+        boxes_to_move.clear();
+        positions_to_check.clear();
+    }
+}
+
+pub fn sum_scaled_box_coordinates(input: &Input) -> usize {
+    let (mut map, mut robot_position) = create_map(&input.warehouse_map, 2);
+    execute_moves_part_2(&mut map, &mut robot_position, &input.moves);
+
+    collect_box_coordinates(&map)
+}
+
+pub fn warehouse_frame(map: &Grid<TileType>, robot_position: Point) -> Frame {
+    let mut frame = Frame::from_grid(map, |tile| match tile {
+        TileType::Empty => Cell::new('.', Color::DARK_GRAY),
+        TileType::Wall => Cell::new('#', Color::GRAY),
+        TileType::BoxLeft => Cell::new('[', Color::ORANGE),
+        TileType::BoxRight => Cell::new(']', Color::ORANGE),
+    });
+    frame.cells[robot_position] = Cell::new('@', Color::YELLOW);
+    frame
+}
+
+/// The widened warehouse of part 2 before the first move and after each one.
+pub fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+    let (mut map, mut robot_position) = create_map(&input.warehouse_map, 2);
+    emit(warehouse_frame(&map, robot_position));
+
+    for (index, direction) in input.moves.iter().enumerate() {
+        execute_moves_part_2(&mut map, &mut robot_position, std::slice::from_ref(direction));
+        let label = format!("move {}/{}", index + 1, input.moves.len());
+        emit(warehouse_frame(&map, robot_position).label(Point::ORIGIN, label, Color::WHITE));
+    }
+}
+
+/// A `size`×`size` walled warehouse, about a fifth of it boxes, followed by eight
+/// moves per tile in lines of 1000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let cells = (0..size * size)
+        .map(|index| {
+            let (row, col) = (index / size, index % size);
+            if row == 0 || col == 0 || row == size - 1 || col == size - 1 || rng.chance(1, 20) {
+                '#'
+            } else if rng.chance(1, 5) {
+                'O'
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    let mut warehouse = Grid::from_vec(size, cells);
+    warehouse[(1 + rng.index(size - 2), 1 + rng.index(size - 2))] = '@';
+
+    let moves: Vec<char> = (0..size * size * 8).map(|_| *rng.choose(&['^', 'v', '<', '>'])).collect();
+    let lines: Vec<String> = moves.chunks(1000).map(|line| line.iter().collect()).collect();
+
+    format!("{}\n\n{}\n", warehouse, lines.join("\n"))
+}
+
+pub fn part1(input: &Input) -> Answer {
+    sum_box_coordinates(input).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    sum_scaled_box_coordinates(input).into()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 15;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+        visualize(input, emit)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&parse(SMALL_EXAMPLE).unwrap()), Answer::Integer(2028));
+        assert_eq!(part1(&parse(LARGE_EXAMPLE).unwrap()), Answer::Integer(10092));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(LARGE_EXAMPLE).unwrap()), Answer::Integer(9021));
+    }
+
+    #[test]
+    fn visualization_shows_every_move_on_the_wide_map() {
+        let input = parse(LARGE_EXAMPLE).unwrap();
+        let mut frames = Vec::new();
+        visualize(&input, &mut |frame| frames.push(frame));
+
+        assert_eq!(frames.len(), input.moves.len() + 1);
+        assert_eq!((frames[0].cells.width(), frames[0].cells.height()), (20, 10));
+        assert_eq!(frames[0].cells[(4, 8)], Cell::new('@', Color::YELLOW));
+        assert_eq!(frames[0].cells[(1, 12)], Cell::new('[', Color::ORANGE));
+    }
+
+    #[test]
+    fn invalid_tiles_and_moves_report_position() {
+        let error = parse("####\n#@x#\n####\n\n<>").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+
+        let error = parse("####\n#@.#\n####\n\n<>\n^?").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (6, 2, "?"));
+    }
+
+    #[test]
+    fn warehouse_must_be_walled_in() {
+        let error = parse("@\n\n^\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "the warehouse must be surrounded by walls");
+
+        let error = parse("####\n#@..\n####\n\n>").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, "."));
+    }
+}
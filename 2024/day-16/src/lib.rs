@@ -0,0 +1,252 @@
+use aoc_common::frame::{Cell, Color, Frame};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use aoc_common::search::{dijkstra, Search};
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use std::collections::HashSet;
+
+pub use aoc_common::grid::Position;
+
+pub type Reindeer = (Position, Direction);
+
+pub struct Input {
+    pub maze: Grid<char>,
+    pub start: Position,
+    pub end: Position,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let maze = Grid::parse(input)?;
+    let start = maze.find(&'S').ok_or_else(|| ParseError::at_end(input, "no start tile (S) in the maze"))?;
+    let end = maze.find(&'E').ok_or_else(|| ParseError::at_end(input, "no end tile (E) in the maze"))?;
+
+    Ok(Input { maze, start, end })
+}
+
+pub fn moves(maze: &Grid<char>, (position, facing): Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut moves = vec![((position, facing.turn_left()), 1000), ((position, facing.turn_right()), 1000)];
+
+    if let Some(next) = maze.step(position, facing).filter(|&next| maze[next] != '#') {
+        moves.push(((next, facing), 1));
+    }
+
+    moves
+}
+
+pub fn search_maze(maze: &Grid<char>, start: Position, end: Position) -> Search<Reindeer> {
+    let graph = |&reindeer: &Reindeer| moves(maze, reindeer);
+    dijkstra(&graph, (start, Direction::Right), |&(position, _)| position == end)
+}
+
+pub fn best_path_tiles(search: &Search<Reindeer>) -> HashSet<Position> {
+    search
+        .nodes_on_shortest_paths(search.goals().iter().copied())
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Returns the lowest score and the number of tiles on any lowest-scoring path,
+/// or `None` when walls cut the end off.
+pub fn find_lowest_score(maze: &Grid<char>, start: Position, end: Position) -> Option<(usize, usize)> {
+    let search = search_maze(maze, start, end);
+    let best_score = search.goal_cost()?;
+
+    Some((best_score, best_path_tiles(&search).len()))
+}
+
+/// The maze with every tile on a best path highlighted and one of those paths drawn.
+pub fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+    let search = search_maze(&input.maze, input.start, input.end);
+    let mut frame = Frame::from_grid(&input.maze, |&c| match c {
+        '#' => Cell::new('#', Color::GRAY),
+        _ => Cell::new(c, Color::DARK_GRAY),
+    });
+
+    if let (Some(score), Some(&goal)) = (search.goal_cost(), search.goals().first()) {
+        let tiles = best_path_tiles(&search);
+        let mut route: Vec<Point> = search
+            .path(goal)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(position, _)| Point::from_position(position))
+            .collect();
+        // Turning in place repeats a tile.
+        route.dedup();
+
+        let label = format!("score {} tiles {}", score, tiles.len());
+        frame = frame
+            .highlight(tiles.into_iter().map(Point::from_position).collect(), Color::BLUE)
+            .path(route, Color::YELLOW)
+            .label(Point::ORIGIN, label, Color::WHITE);
+    }
+
+    emit(frame);
+}
+
+/// A `size`×`size` maze, rounded up to odd, with S in the bottom left and E in the top
+/// right. A spanning tree of corridors guarantees a path, and a few extra openings give
+/// it loops so several paths can tie.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut maze = Grid::new(size, size, '#');
+    let start = (size - 2, 1);
+    maze[start] = '.';
+
+    let mut stack = vec![start];
+    while let Some(&position) = stack.last() {
+        let unvisited: Vec<(isize, isize)> = Direction::ALL
+            .into_iter()
+            .map(|direction| direction.movement())
+            .filter(|&(dr, dc)| maze.offset(position, (2 * dr, 2 * dc)).is_some_and(|next| maze[next] == '#'))
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (dr, dc) = *rng.choose(&unvisited);
+        let wall = maze.offset(position, (dr, dc)).unwrap();
+        let next = maze.offset(position, (2 * dr, 2 * dc)).unwrap();
+        maze[wall] = '.';
+        maze[next] = '.';
+        stack.push(next);
+    }
+
+    for _ in 0..size * size / 50 {
+        maze[(1 + rng.index(size - 2), 1 + rng.index(size - 2))] = '.';
+    }
+    maze[start] = 'S';
+    maze[(1, size - 2)] = 'E';
+
+    format!("{}\n", maze)
+}
+
+pub fn part1(input: &Input) -> Answer {
+    find_lowest_score(&input.maze, input.start, input.end).map(|(score, _)| score).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    find_lowest_score(&input.maze, input.start, input.end).map(|(_, tiles)| tiles).into()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 16;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+        visualize(input, emit)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Overlay;
+
+    const EXAMPLE_1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1(&parse(EXAMPLE_1).unwrap()), Answer::Integer(7036));
+        assert_eq!(part1(&parse(EXAMPLE_2).unwrap()), Answer::Integer(11048));
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2(&parse(EXAMPLE_1).unwrap()), Answer::Integer(45));
+        assert_eq!(part2(&parse(EXAMPLE_2).unwrap()), Answer::Integer(64));
+    }
+
+    #[test]
+    fn unreachable_end_has_no_score() {
+        let input = parse("#..E#\n###.#\n#S#.#\n").unwrap();
+        assert_eq!((part1(&input), part2(&input)), (Answer::None, Answer::None));
+    }
+
+    #[test]
+    fn generated_mazes_can_be_solved() {
+        for seed in 0..20 {
+            let input = parse(&generate(&mut Rng::new(seed), 40)).unwrap();
+            assert_eq!(input.maze.width(), 41);
+            assert!(search_maze(&input.maze, input.start, input.end).goal_cost().is_some());
+        }
+    }
+
+    #[test]
+    fn visualization_draws_a_best_path_from_start_to_end() {
+        let mut frames = Vec::new();
+        visualize(&parse(EXAMPLE_1).unwrap(), &mut |frame| frames.push(frame));
+
+        let [frame] = &frames[..] else { panic!("expected one frame, got {}", frames.len()) };
+        match &frame.overlays[..] {
+            [
+                Overlay::Highlight { points: tiles, .. },
+                Overlay::Path { points: route, .. },
+                Overlay::Label { text, .. },
+            ] => {
+                assert_eq!(tiles.len(), 45);
+                assert_eq!((route[0], route[route.len() - 1]), (Point::new(1, 13), Point::new(13, 1)));
+                assert_eq!(text, "score 7036 tiles 45");
+            }
+            overlays => panic!("unexpected overlays {:?}", overlays),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::solution::run_main::<y2024_day_16::Day16>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,284 @@
+use aoc_common::parse::{block_array, field, parse_token};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+
+pub struct Input {
+    pub reg_a: i64,
+    pub reg_b: i64,
+    pub reg_c: i64,
+    pub program: Vec<u8>,
+}
+
+pub fn get_solution(
+    reg_a: i64,
+    reg_b: i64,
+    reg_c: i64,
+    expected_output: &[u8],
+    depth: i32,
+    results: &mut Vec<i64>,
+) {
+    const VALID_MODIFICATIONS: [i64; 8] = [0b000, 0b001, 0b010, 0b011, 0b100, 0b101, 0b110, 0b111];
+
+    if depth < 0 {
+        results.push(reg_a);
+        return;
+    }
+
+    let chunk_position = depth * 3;
+    let chunk_mask = 0b111 << chunk_position;
+
+    for &modification in &VALID_MODIFICATIONS {
+        let new_reg_a = (reg_a & !chunk_mask) | (modification << chunk_position);
+
+        let output = execute_program(
+            &mut new_reg_a.clone(),
+            &mut reg_b.clone(),
+            &mut reg_c.clone(),
+            expected_output,
+        );
+
+        if output.is_some_and(|output| compare_outputs(expected_output, &output, depth)) {
+            get_solution(new_reg_a, reg_b, reg_c, expected_output, depth - 1, results);
+        }
+    }
+}
+
+pub fn compare_outputs(expected: &[u8], actual: &[i64], depth: i32) -> bool {
+    if expected.len() != actual.len() {
+        return false;
+    }
+    expected[depth as usize..]
+        .iter()
+        .enumerate()
+        .all(|(i, &p)| p as i64 == actual[i + depth as usize])
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let [registers, program] = block_array(input)?;
+    let mut registers = registers.lines();
+
+    let reg_a = parse_register(input, &mut registers, "Register A")?;
+    let reg_b = parse_register(input, &mut registers, "Register B")?;
+    let reg_c = parse_register(input, &mut registers, "Register C")?;
+
+    let tokens: Vec<&str> = field(input, program, "Program")?.split(',').map(str::trim).collect();
+    let program = tokens
+        .iter()
+        .map(|&x| match parse_token(input, x)? {
+            value @ 0..=7 => Ok(value),
+            _ => Err(ParseError::at(input, x, "instructions are 3-bit numbers")),
+        })
+        .collect::<ParseResult<Vec<u8>>>()?;
+    check_combo_operands(input, &tokens, &program)?;
+
+    Ok(Input { reg_a, reg_b, reg_c, program })
+}
+
+/// Rejects the reserved combo operand 7 in any instruction the program can reach,
+/// following jumps to every target they name.
+fn check_combo_operands(input: &str, tokens: &[&str], program: &[u8]) -> ParseResult<()> {
+    let mut reached = vec![false; program.len()];
+    let mut pending = vec![0];
+
+    while let Some(pointer) = pending.pop() {
+        if pointer + 1 >= program.len() || reached[pointer] {
+            continue;
+        }
+        reached[pointer] = true;
+
+        let (opcode, operand) = (program[pointer], program[pointer + 1]);
+        if matches!(opcode, 0 | 2 | 5 | 6 | 7) && operand == 7 {
+            return Err(ParseError::at(input, tokens[pointer + 1], "combo operand 7 is reserved"));
+        }
+        if opcode == 3 {
+            pending.push(operand as usize);
+        }
+        pending.push(pointer + 2);
+    }
+
+    Ok(())
+}
+
+pub fn parse_register<'a, I>(input: &str, lines: &mut I, name: &str) -> ParseResult<i64>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, format!("missing {}", name)))?;
+
+    parse_token(input, field(input, line, name)?)
+}
+
+pub fn get_combo_value(operand: u8, reg_a: &i64, reg_b: &i64, reg_c: &i64) -> i64 {
+    match operand {
+        0..=3 => operand as i64,
+        4 => *reg_a,
+        5 => *reg_b,
+        6 => *reg_c,
+        _ => panic!("Invalid combo operand: {}", operand),
+    }
+}
+
+/// `value` divided by 2 to the power of `exponent`, which is 0 once the divisor
+/// no longer fits in an `i64`.
+fn divide_by_power_of_two(value: i64, exponent: i64) -> i64 {
+    u32::try_from(exponent)
+        .ok()
+        .and_then(|exponent| 2_i64.checked_pow(exponent))
+        .map_or(0, |divisor| value / divisor)
+}
+
+/// Instructions a program may run before it is taken to loop forever.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+/// Runs until the instruction pointer leaves the program; `None` if that takes
+/// more than [`STEP_LIMIT`] instructions.
+pub fn execute_program(
+    reg_a: &mut i64,
+    reg_b: &mut i64,
+    reg_c: &mut i64,
+    program: &[u8],
+) -> Option<Vec<i64>> {
+    let mut instruction_pointer = 0;
+    let mut output = Vec::new();
+
+    for _ in 0..STEP_LIMIT {
+        let (Some(&opcode), Some(&operand)) = (program.get(instruction_pointer), program.get(instruction_pointer + 1))
+        else {
+            return Some(output);
+        };
+        instruction_pointer += 2;
+
+        match opcode {
+            0 => *reg_a = divide_by_power_of_two(*reg_a, get_combo_value(operand, reg_a, reg_b, reg_c)),
+            1 => *reg_b ^= operand as i64,
+            2 => *reg_b = get_combo_value(operand, reg_a, reg_b, reg_c) % 8,
+            3 => if *reg_a != 0 { instruction_pointer = operand as usize },
+            4 => *reg_b ^= *reg_c,
+            5 => output.push(get_combo_value(operand, reg_a, reg_b, reg_c) % 8),
+            6 => *reg_b = divide_by_power_of_two(*reg_a, get_combo_value(operand, reg_a, reg_b, reg_c)),
+            7 => *reg_c = divide_by_power_of_two(*reg_a, get_combo_value(operand, reg_a, reg_b, reg_c)),
+            _ => panic!("Invalid opcode: {}", opcode),
+        }
+    }
+
+    None
+}
+
+/// A program shaped like the puzzle's, which prints one octal digit of A per loop,
+/// with random constants, and an A register of `size` octal digits (at most 20).
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 20) as u32;
+    let reg_a = rng.between(8_i64.pow(digits - 1), 8_i64.pow(digits) - 1);
+    let program = [2, 4, 1, rng.between(0, 7), 7, 5, 1, rng.between(0, 7), 4, rng.between(0, 7), 0, 3, 5, 5, 3, 0];
+    let program: Vec<String> = program.iter().map(i64::to_string).collect();
+
+    format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", reg_a, program.join(","))
+}
+
+pub fn part1(input: &Input) -> Answer {
+    execute_program(&mut input.reg_a.clone(), &mut input.reg_b.clone(), &mut input.reg_c.clone(), &input.program).into()
+}
+
+/// Octal digits of A that fit in an `i64`, and so the longest program part 2 can reproduce.
+pub const MAX_DIGITS: usize = 21;
+
+pub fn part2(input: &Input) -> Answer {
+    if input.program.len() > MAX_DIGITS {
+        return Answer::None;
+    }
+
+    let reg_a = 0b000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000;
+    let mut results = Vec::new();
+    let depth = input.program.len() as i32 - 1;
+    get_solution(reg_a, input.reg_b, input.reg_c, &input.program, depth, &mut results);
+
+    results.into_iter().min().into()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 17;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const EXAMPLE_2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE_1).unwrap()), Answer::List(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE_2).unwrap()), Answer::Integer(117440));
+    }
+
+    #[test]
+    fn missing_program_is_an_error() {
+        let error = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n").err().unwrap();
+        assert_eq!(error.message, "expected 2 blank-line separated sections, found 1");
+
+        let error = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (5, 12, "8"));
+    }
+
+    #[test]
+    fn reserved_combo_operand_is_an_error_where_reachable() {
+        let error = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,4,1,1,5,7").err().unwrap();
+        assert_eq!((error.column, error.message.as_str()), (20, "combo operand 7 is reserved"));
+
+        let input = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,5,7,4,5,4").unwrap();
+        assert_eq!(part1(&input), Answer::List(vec![1]));
+    }
+
+    #[test]
+    fn odd_length_and_endless_programs_do_not_panic() {
+        let input = parse("Register A: 9\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0").unwrap();
+        assert_eq!(part1(&input), Answer::List(vec![1]));
+
+        let input = parse("Register A: 269\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,4,7,5,1,0,3,0").unwrap();
+        assert_eq!(part1(&input), Answer::None);
+
+        let input = "Register A: 9223372036854775807\nRegister B: 0\nRegister C: 0\n\nProgram: 0,4,0,6,7,4,5,6";
+        assert_eq!(part1(&parse(input).unwrap()), Answer::List(vec![0]));
+    }
+}
@@ -0,0 +1,305 @@
+use aoc_common::frame::{Cell, Color, Frame};
+use aoc_common::geometry::Point;
+use aoc_common::grid::Grid;
+use aoc_common::parse::{each_line, int_pattern, non_empty_lines};
+use aoc_common::rng::Rng;
+use aoc_common::search::{self, Search};
+use aoc_common::{Answer, Params, ParseError, ParseResult, Solution};
+use std::io;
+
+pub struct Input {
+    pub bytes: Vec<Point>,
+    pub grid_size: usize,
+    /// Bytes that have fallen when part 1 looks for a path.
+    pub byte_limit: usize,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    parse_with(input, &Params::default())
+}
+
+pub fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+    let grid_size = params.get_positive("grid_size", GRID_SIZE)?;
+    let bytes = non_empty_lines(input)
+        .map(|line| parse_byte(input, line, grid_size))
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Input { bytes, grid_size, byte_limit: params.get("byte_limit", BYTE_LIMIT)? })
+}
+
+/// Like [`parse_with`], reading one byte position at a time.
+pub fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, params: &Params) -> ParseResult<Input> {
+    let grid_size = params.get_positive("grid_size", GRID_SIZE)?;
+    let bytes = each_line(lines, |line| parse_byte(line, line, grid_size))?;
+
+    Ok(Input { bytes, grid_size, byte_limit: params.get("byte_limit", BYTE_LIMIT)? })
+}
+
+fn parse_byte(input: &str, line: &str, grid_size: usize) -> ParseResult<Point> {
+    let [x, y] = int_pattern::<u32, 2>(input, line, "{},{}")?;
+    if x as usize >= grid_size || y as usize >= grid_size {
+        let message = format!("byte falls outside the {}x{} memory space", grid_size, grid_size);
+        return Err(ParseError::at(input, line, message));
+    }
+    Ok(Point::new(x.into(), y.into()))
+}
+
+pub fn initialize_corrupted(bytes: &[Point], grid_size: usize, limit: usize) -> Grid<bool> {
+    let mut corrupted = Grid::new(grid_size, grid_size, false);
+    for &byte in bytes.iter().take(limit) {
+        corrupted[byte] = true;
+    }
+
+    corrupted
+}
+
+pub fn search_exit(corrupted: &Grid<bool>, start: Point, end: Point) -> Search<Point> {
+    let open_neighbors = |&position: &Point| {
+        position
+            .neighbors4()
+            .filter(|&next| corrupted.get_point(next) == Some(&false))
+            .map(|next| (next, 1))
+    };
+
+    search::bfs(&open_neighbors, start, |&position| position == end)
+}
+
+pub fn bfs(corrupted: &Grid<bool>, start: Point, end: Point) -> Option<usize> {
+    search_exit(corrupted, start, end).goal_cost()
+}
+
+pub fn find_first_blocking_byte(grid_size: usize, bytes: &[Point], start: Point, end: Point) -> Option<Point> {
+    let is_blocked = |count| bfs(&initialize_corrupted(bytes, grid_size, count), start, end).is_none();
+
+    // Once the exit is cut off it stays cut off, so binary search for the first blocking count.
+    let (mut open, mut blocked) = (0, bytes.len());
+    if blocked == 0 || !is_blocked(blocked) {
+        return None;
+    }
+    while blocked - open > 1 {
+        let middle = (open + blocked) / 2;
+        if is_blocked(middle) {
+            blocked = middle;
+        } else {
+            open = middle;
+        }
+    }
+
+    Some(bytes[blocked - 1])
+}
+
+/// The first blocking byte found by searching again after every byte falls.
+pub fn first_blocking_byte_by_scan(grid_size: usize, bytes: &[Point], start: Point, end: Point) -> Option<Point> {
+    let mut corrupted = Grid::new(grid_size, grid_size, false);
+    bytes.iter().copied().find(|&byte| {
+        corrupted[byte] = true;
+        bfs(&corrupted, start, end).is_none()
+    })
+}
+
+pub const GRID_SIZE: usize = 71;
+pub const BYTE_LIMIT: usize = 1024;
+
+pub fn exit(grid_size: usize) -> Point {
+    Point::new(grid_size as i64 - 1, grid_size as i64 - 1)
+}
+
+/// One frame per fallen byte, with a shortest route to the exit, until the bytes cut it off.
+pub fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+    let (start, end) = (Point::ORIGIN, exit(input.grid_size));
+    let mut corrupted = Grid::new(input.grid_size, input.grid_size, false);
+    let mut route = search_exit(&corrupted, start, end).path(end);
+
+    for (index, &byte) in input.bytes.iter().enumerate() {
+        corrupted[byte] = true;
+        // Bytes that miss the current route cannot make it longer.
+        if route.as_ref().is_some_and(|route| route.contains(&byte)) {
+            route = search_exit(&corrupted, start, end).path(end);
+        }
+
+        let mut frame = Frame::from_grid(&corrupted, |&corrupt| match corrupt {
+            true => Cell::new('#', Color::RED),
+            false => Cell::new('.', Color::DARK_GRAY),
+        });
+        if let Some(route) = &route {
+            frame = frame.path(route.clone(), Color::GREEN);
+        }
+        let label = format!("byte {} at {}", index + 1, byte);
+        emit(frame.highlight(vec![byte], Color::YELLOW).label(Point::ORIGIN, label, Color::WHITE));
+
+        if route.is_none() {
+            break;
+        }
+    }
+}
+
+/// `size` bytes falling on distinct cells of the real puzzle's 71×71 memory space,
+/// never on the start or the exit, so at most 5039 of them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cells: Vec<Point> = (0..GRID_SIZE * GRID_SIZE)
+        .map(|index| Point::new((index % GRID_SIZE) as i64, (index / GRID_SIZE) as i64))
+        .filter(|&cell| cell != Point::ORIGIN && cell != exit(GRID_SIZE))
+        .collect();
+    rng.shuffle(&mut cells);
+    cells.truncate(size);
+
+    cells.iter().map(|cell| format!("{}\n", cell)).collect()
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let corrupted = initialize_corrupted(&input.bytes, input.grid_size, input.byte_limit);
+    bfs(&corrupted, Point::ORIGIN, exit(input.grid_size)).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    find_first_blocking_byte(input.grid_size, &input.bytes, Point::ORIGIN, exit(input.grid_size)).into()
+}
+
+pub fn reference(input: &Input, part: u8) -> Option<Answer> {
+    let (start, end) = (Point::ORIGIN, exit(input.grid_size));
+    (part == 2).then(|| first_blocking_byte_by_scan(input.grid_size, &input.bytes, start, end).into())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 18;
+
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["grid_size", "byte_limit"];
+
+    const LINE_ORIENTED: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> ParseResult<Input> {
+        parse_with(input, params)
+    }
+
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, params: &Params) -> ParseResult<Input> {
+        parse_lines(lines, params)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn reference(input: &Input, part: u8) -> Option<Answer> {
+        reference(input, part)
+    }
+
+    fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+        visualize(input, emit)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Overlay;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn part1_example() {
+        let corrupted = initialize_corrupted(&parse(EXAMPLE).unwrap().bytes, 7, 12);
+        assert_eq!(bfs(&corrupted, Point::ORIGIN, exit(7)), Some(22));
+    }
+
+    #[test]
+    fn part2_example() {
+        let bytes = parse(EXAMPLE).unwrap().bytes;
+        assert_eq!(find_first_blocking_byte(7, &bytes, Point::ORIGIN, exit(7)), Some(Point::new(6, 1)));
+    }
+
+    #[test]
+    fn streamed_lines_parse_like_text() {
+        let params = Params::new().with("grid_size", "7");
+        let input = parse_lines(&mut EXAMPLE.lines().map(|line| Ok(line.to_string())), &params).unwrap();
+        assert_eq!(input.bytes, parse_with(EXAMPLE, &params).unwrap().bytes);
+
+        let error = parse_lines(&mut ["1,2", "7,0"].into_iter().map(|line| Ok(line.to_string())), &params);
+        assert_eq!(error.err().unwrap().to_string(), "<input>:2:1: byte falls outside the 7x7 memory space: `7,0`");
+    }
+
+    #[test]
+    fn visualization_stops_at_the_first_blocking_byte() {
+        let input = parse_with(EXAMPLE, &Params::new().with("grid_size", "7")).unwrap();
+        let mut frames = Vec::new();
+        visualize(&input, &mut |frame| frames.push(frame));
+
+        assert_eq!(frames.len(), 21);
+        assert!(matches!(&frames[11].overlays[0], Overlay::Path { points, .. } if points.len() == 23));
+        assert!(matches!(&frames[20].overlays[0], Overlay::Highlight { points, .. } if points == &[Point::new(6, 1)]));
+    }
+
+    #[test]
+    fn example_sizes_come_from_params() {
+        let params = Params::new().with("grid_size", "7").with("byte_limit", "12");
+        let input = parse_with(EXAMPLE, &params).unwrap();
+
+        assert_eq!(part1(&input), Answer::Integer(22));
+        assert_eq!(part2(&input), Answer::Coordinate(6, 1));
+    }
+
+    #[test]
+    fn memory_spaces_must_have_a_size() {
+        let params = Params::new().with("grid_size", "0");
+        assert_eq!(parse_with("", &params).err().unwrap().message, "must be at least 1");
+        assert!(parse_lines(&mut std::iter::empty(), &params).is_err());
+        assert_eq!(find_first_blocking_byte(7, &[], Point::ORIGIN, Point::new(9, 9)), None);
+    }
+
+    #[test]
+    fn bytes_outside_the_memory_space_are_errors() {
+        let error = parse_with("1,2\n6,7\n", &Params::new().with("grid_size", "7")).err().unwrap();
+        assert_eq!((error.line, error.text.as_str()), (2, "6,7"));
+        assert_eq!(error.message, "byte falls outside the 7x7 memory space");
+    }
+
+    #[test]
+    fn bytes_must_be_two_numbers() {
+        let error = parse("5,4\n4,2a\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "2a"));
+        assert!(parse("5,4,3\n").is_err());
+        assert!(parse("5 4\n").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::parse::{each_line, ints, non_empty_lines};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+use std::io;
+
+pub type Input = Vec<Vec<i32>>;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    non_empty_lines(input).map(|line| ints(input, line)).collect()
+}
+
+/// Like [`parse`], reading one report at a time.
+pub fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>) -> ParseResult<Input> {
+    each_line(lines, |line| ints(line, line))
+}
+
+/// Steps are taken in i64, since two i32 levels can lie further apart than an i32 holds.
+pub fn is_safe(report: &[i32]) -> bool {
+    let diffs: Vec<i64> = report
+        .windows(2)
+        .map(|r| i64::from(r[1]) - i64::from(r[0]))
+        .collect();
+    let all_increasing = diffs
+        .iter()
+        .all(|d| (1..=3).contains(d));
+    let all_decreasing = diffs
+        .iter()
+        .all(|d| (-3..=-1).contains(d));
+
+    all_increasing || all_decreasing
+}
+
+/// Index of the first step that is not a rise (or, when `falling`, a fall) of 1 to 3.
+fn first_bad_step(report: &[i32], falling: bool) -> Option<usize> {
+    report.windows(2).position(|pair| {
+        let (from, to) = (i64::from(pair[0]), i64::from(pair[1]));
+        let step = if falling { from - to } else { to - from };
+        !(1..=3).contains(&step)
+    })
+}
+
+/// Removing a level before the first bad step leaves that step in place, so only
+/// its two levels are worth removing, once for each direction.
+pub fn is_safe_with_dampener(report: &[i32]) -> bool {
+    [false, true].into_iter().any(|falling| match first_bad_step(report, falling) {
+        None => true,
+        Some(step) => [step, step + 1].into_iter().any(|removed| {
+            let mut modified_report = report.to_vec();
+            modified_report.remove(removed);
+            first_bad_step(&modified_report, falling).is_none()
+        }),
+    })
+}
+
+/// The dampener as described: retries the report without each level in turn.
+pub fn is_safe_by_removal(report: &[i32]) -> bool {
+    if is_safe(report) {
+        return true;
+    }
+    for i in 0..report.len() {
+        let mut modified_report = report.to_vec();
+        modified_report.remove(i);
+        if is_safe(&modified_report) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// `size` reports of 5 to 8 levels that climb or fall steadily, half with one level knocked out of line.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(1, 2) { 1 } else { -1 };
+        let mut levels = vec![rng.between(25, 75)];
+        for _ in 1..rng.between(5, 8) {
+            levels.push(levels[levels.len() - 1] + direction * rng.between(1, 3));
+        }
+        if rng.chance(1, 2) {
+            let index = rng.index(levels.len());
+            levels[index] += rng.between(-4, 4);
+        }
+
+        let levels: Vec<String> = levels.iter().map(i64::to_string).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
+
+pub fn part1(reports: &Input) -> Answer {
+    reports
+        .iter()
+        .filter(|report| is_safe(report))
+        .count()
+        .into()
+}
+
+pub fn part2(reports: &Input) -> Answer {
+    reports
+        .iter()
+        .filter(|report| is_safe_with_dampener(report))
+        .count()
+        .into()
+}
+
+pub fn reference(reports: &Input, part: u8) -> Option<Answer> {
+    (part == 2).then(|| reports.iter().filter(|report| is_safe_by_removal(report)).count().into())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
+
+    type Input = Input;
+
+    const LINE_ORIENTED: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, _: &Params) -> ParseResult<Input> {
+        parse_lines(lines)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn reference(input: &Input, part: u8) -> Option<Answer> {
+        reference(input, part)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(4));
+    }
+
+    #[test]
+    fn extreme_levels_do_not_overflow() {
+        let reports = parse("2147483647 -2147483648\n-2147483648 2147483647 2147483646\n").unwrap();
+        assert_eq!(part1(&reports), Answer::Integer(0));
+        assert_eq!(part2(&reports), Answer::Integer(2));
+        assert_eq!(reference(&reports, 2), Some(Answer::Integer(2)));
+    }
+
+    #[test]
+    fn non_numeric_levels_are_errors() {
+        let error = parse("1 2 3\n7 6 4 x 2 1\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 7, "x"));
+    }
+}
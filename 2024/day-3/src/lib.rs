@@ -0,0 +1,160 @@
+use aoc_common::parse::parse_token;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseResult, Solution};
+use regex::Regex;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+pub type Input = Vec<Instruction>;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let multiplication_regex = Regex::new(r"^mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+    let do_regex = Regex::new(r"^do\(\)").unwrap();
+    let dont_regex = Regex::new(r"^don't\(\)").unwrap();
+
+    let mut instructions = Vec::new();
+    let mut position = 0;
+
+    while position < input.len() {
+        let slice = &input[position..];
+
+        if let Some(capture) = multiplication_regex.captures(slice) {
+            let x: i32 = parse_token(input, &slice[capture.get(1).unwrap().range()])?;
+            let y: i32 = parse_token(input, &slice[capture.get(2).unwrap().range()])?;
+            instructions.push(Instruction::Mul(x, y));
+            position += capture.get(0).unwrap().end();
+        } else if let Some(mat) = do_regex.find(slice) {
+            instructions.push(Instruction::Do);
+            position += mat.end();
+        } else if let Some(mat) = dont_regex.find(slice) {
+            instructions.push(Instruction::Dont);
+            position += mat.end();
+        } else {
+            position += slice.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    Ok(instructions)
+}
+
+pub fn sum_multiplications(instructions: &[Instruction]) -> i64 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(x, y) => i64::from(x * y),
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn sum_enabled_multiplications(instructions: &[Instruction]) -> i64 {
+    let mut is_enabled = true;
+    let mut total_sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(x, y) => {
+                if is_enabled {
+                    total_sum += i64::from(x * y);
+                }
+            }
+            Instruction::Do => is_enabled = true,
+            Instruction::Dont => is_enabled = false,
+        }
+    }
+
+    total_sum
+}
+
+/// Instruction-like text that is not an instruction, as in the corrupted memory.
+const NOISE: &[&str] = &[
+    "mul(4*", "mul[3,7]", "mul ( 2 , 4 )", "?(12,34)", "select()", "when()", "what()", "from()", "how(1,2)", "don't",
+    "do(", "mul(", "!", "@", "#", "^", "&", "*", "'", " ", "[", "]", "{", "}", "<", ">", ",", ";", "%", "/", "+", "-",
+    "(", ")",
+];
+
+/// Roughly `size` characters of corrupted memory with `mul`, `do()` and `don't()` instructions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    while input.len() < size {
+        match rng.below(10) {
+            0..=2 => input.push_str(&format!("mul({},{})", rng.between(1, 999), rng.between(1, 999))),
+            3 => input.push_str("do()"),
+            4 => input.push_str("don't()"),
+            _ => {
+                let noise = rng.choose(NOISE);
+                input.push_str(noise);
+            }
+        }
+    }
+    input.push('\n');
+
+    input
+}
+
+pub fn part1(instructions: &Input) -> Answer {
+    sum_multiplications(instructions).into()
+}
+
+pub fn part2(instructions: &Input) -> Answer {
+    sum_enabled_multiplications(instructions).into()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE_1).unwrap()), Answer::Integer(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE_2).unwrap()), Answer::Integer(48));
+    }
+
+    #[test]
+    fn only_numbers_of_up_to_three_digits_multiply() {
+        assert_eq!(parse("mul(98,42554255)mul(1234,5)").unwrap(), []);
+        assert_eq!(part1(&parse(&"mul(999,999)".repeat(3000)).unwrap()), Answer::Integer(2_994_003_000));
+    }
+
+    #[test]
+    fn non_ascii_digits_are_noise() {
+        assert_eq!(parse("mul(2,4)mul(٣,4)mul(3,3)").unwrap(), [Instruction::Mul(2, 4), Instruction::Mul(3, 3)]);
+    }
+}
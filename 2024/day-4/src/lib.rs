@@ -0,0 +1,117 @@
+use aoc_common::grid::{Grid, ALL_DIRECTIONS};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseResult, Solution};
+
+pub type Input = Grid<char>;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    Grid::parse(input)
+}
+
+pub fn count_word(grid: &Grid<char>, word: &str) -> usize {
+    let word: Vec<char> = word.chars().collect();
+
+    grid.positions()
+        .map(|position| {
+            ALL_DIRECTIONS
+                .iter()
+                .filter(|&&direction| {
+                    let mut letters = grid.ray(position, direction);
+                    word.iter().all(|letter| letters.next() == Some(letter))
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub fn count_x_mas(grid: &Grid<char>) -> usize {
+    let mut count = 0;
+
+    for row in 0..grid.height().saturating_sub(2) {
+        for col in 0..grid.width().saturating_sub(2) {
+            if is_x_mas(grid, row, col) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn is_x_mas(grid: &Grid<char>, row: usize, col: usize) -> bool {
+    let top_left_mas: Vec<char> = grid.diagonal((row, col)).take(3).copied().collect();
+    let top_right_mas: Vec<char> = grid.anti_diagonal((row, col + 2)).take(3).copied().collect();
+
+    let valid_mas = |mas: &[char]| mas == ['M', 'A', 'S'] || mas == ['S', 'A', 'M'];
+
+    valid_mas(&top_left_mas) && valid_mas(&top_right_mas)
+}
+
+/// A `size`×`size` word search of the letters X, M, A and S.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let letters = (0..size * size).map(|_| *rng.choose(&['X', 'M', 'A', 'S'])).collect();
+
+    format!("{}\n", Grid::from_vec(size, letters))
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    count_word(grid, "XMAS").into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    count_x_mas(grid).into()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(18));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(9));
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::parse::{block_array, int_pattern, separated_ints};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseResult, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub type Rule = (i32, i32);
+
+pub struct Input {
+    pub rules: Vec<Rule>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let [rules, updates] = block_array(input)?;
+
+    let rules = rules
+        .lines()
+        .map(|line| int_pattern(input, line, "{}|{}").map(|[before, after]| (before, after)))
+        .collect::<ParseResult<_>>()?;
+    let updates = updates
+        .lines()
+        .map(|line| separated_ints(input, line, ','))
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Input { rules, updates })
+}
+
+pub fn find_correctly_ordered_updates(rules: &[Rule], updates: &[Vec<i32>]) -> (i64, i64) {
+    let mut total_middle_sum = 0;
+    let mut updated_total_middle_sum = 0;
+
+    for update in updates {
+        if is_correctly_ordered(update, rules) {
+            let middle_index = update.len() / 2;
+            total_middle_sum += i64::from(update[middle_index]);
+        } else {
+            let corrected_update = correct_order(update, rules);
+            let middle_index = corrected_update.len() / 2;
+            updated_total_middle_sum += i64::from(corrected_update[middle_index]);
+        }
+    }
+
+    (total_middle_sum, updated_total_middle_sum)
+}
+
+pub fn is_correctly_ordered(update: &[i32], rules: &[Rule]) -> bool {
+    let position_map: HashMap<i32, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(index, &page)| (page, index))
+        .collect();
+
+    for &(before, after) in rules {
+        if let (Some(&pos_before), Some(&pos_after)) = (position_map.get(&before), position_map.get(&after)) {
+            if pos_before >= pos_after {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Puts each page after as many of the others as the rules place before it. With
+/// a rule for every pair this is the one correct order, and unlike sorting with a
+/// pairwise comparison it cannot fail on rules that contradict each other.
+pub fn correct_order(update: &[i32], rules: &[Rule]) -> Vec<i32> {
+    let mut sorted_update = update.to_vec();
+    let rule_set: HashSet<Rule> = rules.iter().cloned().collect();
+
+    sorted_update.sort_by_cached_key(|&page| {
+        update.iter().filter(|&&other| rule_set.contains(&(other, page))).count()
+    });
+
+    sorted_update
+}
+
+/// Ordering rules for every pair of 49 pages, then `size` updates of 5 to 23 pages,
+/// about half of them already in order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<i64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = rules.join("\n") + "\n\n";
+    for _ in 0..size {
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut update);
+        update.truncate(2 * rng.between(2, 11) as usize + 1);
+        if rng.chance(1, 2) {
+            update.sort_unstable();
+        }
+
+        let update: Vec<String> = update.iter().map(|&index| pages[index].to_string()).collect();
+        input.push_str(&update.join(","));
+        input.push('\n');
+    }
+
+    input
+}
+
+pub fn part1(input: &Input) -> Answer {
+    find_correctly_ordered_updates(&input.rules, &input.updates).0.into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    find_correctly_ordered_updates(&input.rules, &input.updates).1.into()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(143));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(123));
+    }
+
+    #[test]
+    fn contradictory_rules_and_empty_updates_do_not_panic() {
+        let input = parse("38|33\n80|70\n\n6,2,2,33,38,1,3,6,6,1,1,6,7,70,3,80,7,8,9,4,6\n").unwrap();
+        assert_eq!(part2(&input), Answer::Integer(6));
+
+        let error = parse("1|2\n\n1,2\n-\n").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (4, "invalid i32"));
+        let error = parse("1|2\n\n1,,2\n").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+}
@@ -0,0 +1,256 @@
+use aoc_common::frame::{Cell, Color, Frame};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::{Grid, Position};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use std::collections::HashSet;
+
+pub struct Input {
+    pub grid: Grid<char>,
+    pub start_position: Position,
+    pub direction: Direction,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let mut grid = Grid::parse(input)?;
+    let (start_position, direction) =
+        find_start(&mut grid).ok_or_else(|| ParseError::at_end(input, "no guard (^, v, < or >) on the map"))?;
+
+    Ok(Input { grid, start_position, direction })
+}
+
+pub fn find_start(grid: &mut Grid<char>) -> Option<(Position, Direction)> {
+    let position = grid.position(|&ch| Direction::from_char(ch).is_some())?;
+    let direction = Direction::from_char(grid[position])?;
+    grid[position] = '.';
+
+    Some((position, direction))
+}
+
+/// The cells the guard visits before leaving the map, or `None` if their route loops.
+pub fn simulate_guard_movement(
+    grid: &Grid<char>,
+    start_position: Position,
+    start_direction: Direction,
+) -> Option<HashSet<Position>> {
+    let mut position = start_position;
+    let mut current_direction = start_direction;
+    let mut visited = HashSet::new();
+    let mut visited_states = HashSet::new();
+
+    visited.insert(position);
+
+    while let Some(next_position) = next_position(grid, position, current_direction) {
+        if !visited_states.insert((position, current_direction)) {
+            return None;
+        }
+
+        match grid[next_position] {
+            '#' | 'O' => current_direction = current_direction.turn_right(),
+            _ => {
+                position = next_position;
+                visited.insert(position);
+            }
+        }
+    }
+
+    Some(visited)
+}
+
+pub fn find_valid_obstructions(
+    grid: &Grid<char>,
+    start_position: Position,
+    start_direction: Direction,
+) -> HashSet<Position> {
+    let mut valid_positions = HashSet::new();
+    let mut test_grid = grid.clone();
+
+    for position in grid.find_all(|&cell| cell == '.') {
+        if position != start_position {
+            test_grid[position] = 'O';
+
+            if is_guard_stuck(&test_grid, start_position, start_direction) {
+                valid_positions.insert(position);
+            }
+
+            test_grid[position] = '.';
+        }
+    }
+
+    valid_positions
+}
+
+pub fn is_guard_stuck(
+    grid: &Grid<char>,
+    start_position: Position,
+    start_direction: Direction,
+) -> bool {
+    let mut position = start_position;
+    let mut current_direction = start_direction;
+    let mut visited_states = HashSet::new();
+
+    while let Some(next_position) = next_position(grid, position, current_direction) {
+        if !visited_states.insert((position, current_direction)) {
+            return true;
+        }
+
+        match grid[next_position] {
+            '#' | 'O' => current_direction = current_direction.turn_right(),
+            _ => position = next_position,
+        }
+    }
+
+    false
+}
+
+pub fn next_position(grid: &Grid<char>, position: Position, direction: Direction) -> Option<Position> {
+    grid.step(position, direction)
+}
+
+/// One frame per step or turn of the guard's walk, with the visited cells highlighted.
+pub fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+    let map = Frame::from_grid(&input.grid, |&c| match c {
+        '#' => Cell::new('#', Color::GRAY),
+        _ => Cell::new('.', Color::DARK_GRAY),
+    });
+    let (mut position, mut direction) = (input.start_position, input.direction);
+    let mut seen = HashSet::from([position]);
+    let mut states = HashSet::new();
+    let mut visited = vec![Point::from_position(position)];
+
+    loop {
+        let mut frame = map.clone().highlight(visited.clone(), Color::BLUE);
+        frame.cells[position] = Cell::new(direction.to_char(), Color::YELLOW);
+        emit(frame.label(Point::ORIGIN, format!("visited {}", visited.len()), Color::WHITE));
+
+        let Some(next) = next_position(&input.grid, position, direction) else { break };
+        if !states.insert((position, direction)) {
+            break;
+        }
+        if input.grid[next] == '#' {
+            direction = direction.turn_right();
+        } else {
+            position = next;
+            if seen.insert(position) {
+                visited.push(Point::from_position(position));
+            }
+        }
+    }
+}
+
+/// A `size`×`size` lab with about one obstruction in ten, and a guard who walks off the map.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let cells = (0..size * size).map(|_| if rng.chance(1, 10) { '#' } else { '.' }).collect();
+        let mut grid = Grid::from_vec(size, cells);
+        let start = (rng.index(size), rng.index(size));
+        grid[start] = '.';
+
+        if !is_guard_stuck(&grid, start, Direction::Up) {
+            grid[start] = '^';
+            return format!("{}\n", grid);
+        }
+    }
+}
+
+pub fn part1(input: &Input) -> Answer {
+    simulate_guard_movement(&input.grid, input.start_position, input.direction).map(|visited| visited.len()).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    find_valid_obstructions(&input.grid, input.start_position, input.direction).len().into()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, emit: &mut dyn FnMut(Frame)) {
+        visualize(input, emit)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Overlay;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(41));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(6));
+    }
+
+    #[test]
+    fn visualization_ends_with_every_visited_cell_highlighted() {
+        let mut frames = Vec::new();
+        visualize(&parse(EXAMPLE).unwrap(), &mut |frame| frames.push(frame));
+
+        let last = frames.last().unwrap();
+        assert!(matches!(&last.overlays[0], Overlay::Highlight { points, .. } if points.len() == 41));
+        assert_eq!(last.cells[(9, 7)], Cell::new('v', Color::YELLOW));
+    }
+
+    #[test]
+    fn generated_guards_leave_the_lab() {
+        for seed in 0..20 {
+            let input = parse(&generate(&mut Rng::new(seed), 30)).unwrap();
+            assert_eq!((input.grid.width(), input.grid.height()), (30, 30));
+            assert!(!is_guard_stuck(&input.grid, input.start_position, input.direction));
+        }
+    }
+
+    #[test]
+    fn guard_walking_in_a_loop_has_no_answer() {
+        let input = parse(".#.\n#^#\n.#.\n").unwrap();
+        assert_eq!(part1(&input), Answer::None);
+
+        let mut frames = 0;
+        visualize(&input, &mut |_| frames += 1);
+        assert_eq!(frames, 5);
+    }
+
+    #[test]
+    fn missing_guard_is_an_error() {
+        let error = parse("..#\n...\n").err().unwrap();
+        assert!(error.message.starts_with("no guard"));
+    }
+}
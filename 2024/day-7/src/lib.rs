@@ -0,0 +1,259 @@
+use aoc_common::parse::{each_line, ints, non_empty_lines, parse_token};
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, Params, ParseResult, Solution};
+use std::collections::HashSet;
+use std::io;
+
+pub type Equation = (i64, Vec<i64>);
+
+pub type Input = Vec<Equation>;
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    non_empty_lines(input).map(|line| parse_line(input, line.trim())).collect()
+}
+
+/// Like [`parse`], reading one equation at a time.
+pub fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>) -> ParseResult<Input> {
+    each_line(lines, |line| parse_line(line, line.trim()))
+}
+
+pub fn parse_line(input: &str, line: &str) -> ParseResult<Equation> {
+    let (target, numbers) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(input, line, "expected `target: numbers`"))?;
+
+    let target = parse_token(input, target)?;
+    let numbers: Vec<i64> = ints(input, numbers)?;
+    if numbers.is_empty() {
+        return Err(ParseError::at(input, line, "equation has no numbers"));
+    }
+    // The search gives up once the running result passes the target, which only
+    // holds while no operator can make it smaller.
+    if numbers.iter().any(|&number| number < 1) {
+        return Err(ParseError::at(input, line, "equation numbers must be positive"));
+    }
+
+    Ok((target, numbers))
+}
+
+pub fn evaluate_with_operators(target: i64, numbers: &[i64], index: usize, current: i64, allow_concatenation: bool) -> bool {
+    if current > target {
+        return false;
+    }
+    if index == numbers.len() - 1 {
+        return current == target;
+    }
+
+    // A result that overflows, or a concatenation that is no number, cannot reach the target.
+    let next_number = numbers[index + 1];
+    let evaluate_next = |next: Option<i64>| {
+        next.is_some_and(|next| evaluate_with_operators(target, numbers, index + 1, next, allow_concatenation))
+    };
+    evaluate_next(current.checked_add(next_number))
+        || evaluate_next(current.checked_mul(next_number))
+        || (allow_concatenation && evaluate_next(format!("{}{}", current, next_number).parse().ok()))
+}
+
+/// Works back from the target: the last number was added to, multiplied onto or
+/// concatenated onto what the numbers before it make, and each is only possible
+/// for some targets. Targets already ruled out for a prefix are not searched again,
+/// which keeps long equations of small numbers from taking exponential time.
+pub fn can_make(target: i64, numbers: &[i64], allow_concatenation: bool) -> bool {
+    fn search(target: i64, numbers: &[i64], allow_concatenation: bool, ruled_out: &mut HashSet<(usize, i64)>) -> bool {
+        let Some((&last, rest)) = numbers.split_last() else {
+            return false;
+        };
+        if rest.is_empty() {
+            return target == last;
+        }
+        // Positive numbers never make anything below 1.
+        if target < 1 || ruled_out.contains(&(rest.len(), target)) {
+            return false;
+        }
+
+        let mut previous = Vec::with_capacity(3);
+        if target > last {
+            previous.push(target - last);
+        }
+        if target % last == 0 {
+            previous.push(target / last);
+        }
+        let shift = last.checked_ilog10().and_then(|digits| 10_i64.checked_pow(digits + 1));
+        if let Some(shift) = shift.filter(|_| allow_concatenation) {
+            if target > last && (target - last) % shift == 0 {
+                previous.push((target - last) / shift);
+            }
+        }
+
+        let found = previous.into_iter().any(|previous| search(previous, rest, allow_concatenation, ruled_out));
+        if !found {
+            ruled_out.insert((rest.len(), target));
+        }
+        found
+    }
+
+    search(target, numbers, allow_concatenation, &mut HashSet::new())
+}
+
+pub fn total_calibration(equations: &[Equation], allow_concatenation: bool) -> i128 {
+    equations
+        .iter()
+        .filter(|(target, numbers)| can_make(*target, numbers, allow_concatenation))
+        .map(|&(target, _)| i128::from(target))
+        .sum()
+}
+
+/// Tries every combination of operators from the left, as the puzzle describes.
+pub fn reference(equations: &Input, part: u8) -> Option<Answer> {
+    let allow_concatenation = part == 2;
+    let total: i128 = equations
+        .iter()
+        .filter(|(target, numbers)| {
+            // Parsed equations always have numbers, but `Input` can be built by hand.
+            let evaluate = |&first| evaluate_with_operators(*target, numbers, 0, first, allow_concatenation);
+            numbers.first().is_some_and(evaluate)
+        })
+        .map(|&(target, _)| i128::from(target))
+        .sum();
+
+    Some(total.into())
+}
+
+/// Largest test value generated, so concatenating onto it cannot overflow.
+const GENERATED_LIMIT: i64 = 1_000_000_000_000_000;
+
+/// `size` equations of 3 to 12 numbers; about two thirds of them can be made true.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let numbers: Vec<i64> = (0..rng.between(3, 12))
+            .map(|_| if rng.chance(3, 4) { rng.between(1, 9) } else { rng.between(10, 999) })
+            .collect();
+
+        let mut target = numbers[0];
+        for &number in &numbers[1..] {
+            let combined = match rng.below(3) {
+                0 => target.checked_mul(number),
+                1 => format!("{}{}", target, number).parse().ok(),
+                _ => None,
+            };
+            target = combined.filter(|&value| value < GENERATED_LIMIT).unwrap_or(target + number);
+        }
+        if rng.chance(1, 3) {
+            target += 1;
+        }
+
+        let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+        input.push_str(&format!("{}: {}\n", target, numbers.join(" ")));
+    }
+
+    input
+}
+
+pub fn part1(equations: &Input) -> Answer {
+    total_calibration(equations, false).into()
+}
+
+pub fn part2(equations: &Input) -> Answer {
+    total_calibration(equations, true).into()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
+
+    type Input = Input;
+
+    const LINE_ORIENTED: bool = true;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, _: &Params) -> ParseResult<Input> {
+        parse_lines(lines)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn reference(input: &Input, part: u8) -> Option<Answer> {
+        reference(input, part)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(3749));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(11387));
+    }
+
+    #[test]
+    fn long_equations_of_small_numbers_finish() {
+        let ones = vec!["1"; 40].join(" ");
+        let equations = parse(&format!("111111111111111111: {}\n41: {}\n", ones, ones)).unwrap();
+        assert_eq!(part1(&equations), Answer::Integer(0));
+        assert_eq!(part2(&equations), Answer::Integer(111111111111111152));
+    }
+
+    #[test]
+    fn numbers_must_be_positive() {
+        let error = parse("5: 6 -1\n").err().unwrap();
+        assert_eq!(error.message, "equation numbers must be positive");
+        assert!(parse("0: 0 0\n").is_err());
+    }
+
+    #[test]
+    fn overflowing_operations_cannot_reach_the_target() {
+        let equations = parse("44111893765: 41292727232 111893765\n").unwrap();
+        assert_eq!(part2(&equations), Answer::Integer(0));
+
+        let equations = parse("9223372036854775807: 9223372036854775807\n9223372036854775807: 9223372036854775807\n");
+        assert_eq!(part1(&equations.unwrap()), Answer::Text("18446744073709551614".to_string()));
+    }
+
+    #[test]
+    fn non_numeric_tokens_are_errors() {
+        let error = parse("190: 10 19 abc\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 12, "abc"));
+        assert!(parse("190: 10,19\n").is_err());
+    }
+
+    #[test]
+    fn equations_without_numbers_are_never_true() {
+        let equations = vec![(0, Vec::new()), (190, vec![10, 19])];
+        assert_eq!(part2(&equations), Answer::Integer(190));
+        assert_eq!(reference(&equations, 2), Some(Answer::Integer(190)));
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_common::geometry::Point;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseResult, Solution};
+use std::collections::HashSet;
+
+pub type Antenna = (Point, char);
+
+pub struct Input {
+    pub antennas: Vec<Antenna>,
+    pub width: usize,
+    pub height: usize,
+}
+
+pub fn parse(input: &str) -> ParseResult<Input> {
+    let grid = Grid::parse(input)?;
+    let antennas = grid
+        .iter()
+        .filter(|(_, ch)| ch.is_ascii_alphanumeric())
+        .map(|(position, &ch)| (Point::from_position(position), ch))
+        .collect();
+
+    Ok(Input { antennas, width: grid.width(), height: grid.height() })
+}
+
+pub fn count_unique_antinodes(antennas: &[Antenna], width: usize, height: usize, use_find_points: bool) -> usize {
+    let mut antinodes = HashSet::new();
+
+    for (i, &(first, freq1)) in antennas.iter().enumerate() {
+        for &(second, freq2) in antennas.iter().skip(i + 1) {
+            if freq1 == freq2 {
+                let delta = second - first;
+
+                if use_find_points {
+                    antinodes.extend(find_points_in_line(first, delta, width, height));
+                } else {
+                    antinodes.extend([first + delta * 2, second - delta * 2]);
+                }
+            }
+        }
+    }
+
+    antinodes.retain(|point| point.within(width, height));
+    antinodes.len()
+}
+
+pub fn find_points_in_line(start: Point, delta: Point, width: usize, height: usize) -> Vec<Point> {
+    let mut points = Vec::new();
+
+    points.extend(generate_points_in_direction(start, delta, width, height));
+    points.extend(generate_points_in_direction(start - delta, -delta, width, height));
+
+    points
+}
+
+pub fn generate_points_in_direction(start: Point, delta: Point, width: usize, height: usize) -> Vec<Point> {
+    std::iter::successors(Some(start), |&point| Some(point + delta))
+        .take_while(|point| point.within(width, height))
+        .collect()
+}
+
+const FREQUENCIES: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J',
+    'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// A `size`×`size` map with an antenna on about one tile in 25.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let cells = (0..size * size).map(|_| if rng.chance(1, 25) { *rng.choose(FREQUENCIES) } else { '.' }).collect();
+
+    format!("{}\n", Grid::from_vec(size, cells))
+}
+
+pub fn part1(input: &Input) -> Answer {
+    count_unique_antinodes(&input.antennas, input.width, input.height, false).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    count_unique_antinodes(&input.antennas, input.width, input.height, true).into()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 8;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(14));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(34));
+    }
+}
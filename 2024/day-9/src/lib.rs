@@ -0,0 +1,179 @@
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, ParseError, ParseResult, Solution};
+use std::collections::HashSet;
+
+pub type Input = Vec<String>;
+
+pub fn parse(disk_map: &str) -> ParseResult<Input> {
+    let mut blocks = Vec::new();
+    let mut current_file_id = 0;
+
+    for (i, (offset, ch)) in disk_map.trim().char_indices().enumerate() {
+        let length = ch.to_digit(10).ok_or_else(|| {
+            let trimmed = disk_map.trim();
+            ParseError::at(disk_map, &trimmed[offset..offset + ch.len_utf8()], "expected a digit")
+        })? as usize;
+        if i % 2 == 0 {
+            for _ in 0..length {
+                blocks.push(current_file_id.to_string());
+            }
+            current_file_id += 1;
+        } else {
+            for _ in 0..length {
+                blocks.push(".".to_string());
+            }
+        }
+    }
+
+    Ok(blocks)
+}
+
+pub fn compact_disk(blocks: &mut [String]) {
+    for i in (0..blocks.len()).rev() {
+        if blocks[i] != "." {
+            let target_index = blocks[..i].iter().position(|block| block == ".");
+
+            if let Some(target) = target_index {
+                blocks[target] = blocks[i].clone();
+                blocks[i] = ".".to_string();
+            }
+        }
+    }
+}
+
+pub fn calculate_checksum(blocks: &[String]) -> u64 {
+    blocks.iter().enumerate().fold(0, |checksum, (pos, block)| {
+        if block != "." {
+            checksum + pos as u64 * block.parse::<u64>().unwrap()
+        } else {
+            checksum
+        }
+    })
+}
+
+pub fn find_free_spaces(blocks: &[String]) -> Vec<(usize, usize)> {
+    let mut free_spaces = Vec::new();
+    let mut start = None;
+
+    for (i, block) in blocks.iter().enumerate() {
+        if block == "." {
+            if start.is_none() {
+                start = Some(i);
+            }
+        } else if let Some(start_index) = start {
+            free_spaces.push((start_index, i - 1));
+            start = None;
+        }
+    }
+
+    if let Some(start_index) = start {
+        free_spaces.push((start_index, blocks.len() - 1));
+    }
+
+    free_spaces
+}
+
+pub fn compact_disk_whole_files(blocks: &mut [String]) {
+    let mut files: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut seen_files = HashSet::new();
+
+    for block in blocks.iter() {
+        if block != "." && !seen_files.contains(block) {
+            let indices: Vec<usize> = blocks.iter().enumerate()
+                .filter(|(_, b)| *b == block)
+                .map(|(index, _)| index)
+                .collect();
+            files.push((block.clone(), indices));
+            seen_files.insert(block.clone());
+        }
+    }
+
+    files.sort_by_key(|file| std::cmp::Reverse(file.0.parse::<i32>().unwrap()));
+
+    for (file_id, indices) in files {
+        let file_size = indices.len();
+        let free_spaces = find_free_spaces(blocks);
+
+        if let Some((start, _end)) = free_spaces.iter()
+            .find(|(start, end)| end - start + 1 >= file_size && *end < indices[0])
+        {
+            let start = *start;
+
+            for block in &mut blocks[start..start + file_size] {
+                *block = file_id.clone();
+            }
+
+            for index in indices {
+                blocks[index] = ".".to_string();
+            }
+        }
+    }
+}
+
+/// A disk map of `size` digits, rounded up to odd so it ends with a file; files are
+/// 1 to 9 blocks long and the free spaces between them 0 to 9.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut disk_map: String = (0..size | 1)
+        .map(|index| {
+            let length = if index % 2 == 0 { rng.between(1, 9) } else { rng.between(0, 9) };
+            char::from_digit(length as u32, 10).unwrap()
+        })
+        .collect();
+    disk_map.push('\n');
+
+    disk_map
+}
+
+pub fn part1(blocks: &Input) -> Answer {
+    let mut blocks = blocks.clone();
+    compact_disk(&mut blocks);
+    calculate_checksum(&blocks).into()
+}
+
+pub fn part2(blocks: &Input) -> Answer {
+    let mut blocks = blocks.clone();
+    compact_disk_whole_files(&mut blocks);
+    calculate_checksum(&blocks).into()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 9;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> ParseResult<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Answer::Integer(1928));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Answer::Integer(2858));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::geometry::Point;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Coordinate(i64, i64),
+    List(Vec<i64>),
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+            Answer::List(values) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                write!(f, "{}", values.join(","))
+            }
+            Answer::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Integers too large for an `i64` become their digits, so they still display right.
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coordinate(x as i64, y as i64)
+    }
+}
+
+impl From<(i64, i64)> for Answer {
+    fn from((x, y): (i64, i64)) -> Self {
+        Answer::Coordinate(x, y)
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(point: Point) -> Self {
+        Answer::Coordinate(point.x, point.y)
+    }
+}
+
+impl From<Vec<i64>> for Answer {
+    fn from(values: Vec<i64>) -> Self {
+        Answer::List(values)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::None, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_from_common_result_types() {
+        assert_eq!(Answer::from(42usize), Answer::Integer(42));
+        assert_eq!(Answer::from(-7i32), Answer::Integer(-7));
+        assert_eq!(Answer::from("6,1"), Answer::Text("6,1".to_string()));
+        assert_eq!(Answer::from(None::<u64>), Answer::None);
+        assert_eq!(Answer::from(Some(3u64)), Answer::Integer(3));
+        assert_eq!(Answer::from((6usize, 1usize)), Answer::Coordinate(6, 1));
+        assert_eq!(Answer::from(vec![4, 6, 3]), Answer::List(vec![4, 6, 3]));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text("18446744073709551615".to_string()));
+    }
+
+    #[test]
+    fn displays_canonical_form() {
+        assert_eq!(Answer::Integer(6327174563252).to_string(), "6327174563252");
+        assert_eq!(Answer::from("hello").to_string(), "hello");
+        assert_eq!(Answer::Coordinate(6, 1).to_string(), "6,1");
+        assert_eq!(Answer::List(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]).to_string(), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(Answer::List(Vec::new()).to_string(), "");
+        assert_eq!(Answer::None.to_string(), "none");
+    }
+}
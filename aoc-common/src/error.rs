@@ -0,0 +1,139 @@
+use std::fmt;
+use std::io;
+use std::process;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A malformed puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// The puzzle parameter holding the offending text, for errors in a parameter
+    /// rather than in the input; its line and column are then not reported.
+    pub param: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Reports `span`, which must be a slice of `input`, as the offending text.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let start = span.as_ptr() as usize;
+        let base = input.as_ptr() as usize;
+        let offset = if start >= base && start + span.len() <= base + input.len() {
+            start - base
+        } else {
+            input.find(span).unwrap_or(input.len())
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        ParseError {
+            file: None,
+            param: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: span.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Reports something missing at the end of `input`.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        ParseError::at(input, &input[input.len()..], message)
+    }
+
+    /// Reports `value`, the whole value of the parameter `name`, as the offending text.
+    pub fn in_param(name: &str, value: &str, message: impl Into<String>) -> Self {
+        ParseError { param: Some(name.to_string()), ..ParseError::at(value, value, message) }
+    }
+
+    /// Reports that `line` of a streamed input could not be read.
+    pub fn unreadable(line: usize, err: io::Error) -> Self {
+        ParseError::at_end("", format!("failed to read line: {}", err)).on_line(line)
+    }
+
+    /// Moves an error found in one line parsed on its own to `line` of the whole input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Prints the diagnostic and exits; meant for the per-day binaries.
+    pub fn exit(self) -> ! {
+        eprintln!("error: {}", self);
+        process::exit(1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.param {
+            Some(param) => write!(f, "parameter `{}`: {}", param, self.message)?,
+            None => write!(
+                f,
+                "{}:{}:{}: {}",
+                self.file.as_deref().unwrap_or("<input>"),
+                self.line,
+                self.column,
+                self.message
+            )?,
+        }
+
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_span_by_line_and_column() {
+        let input = "1 2\n3 x4\n";
+        let token = &input[6..8];
+        let error = ParseError::at(input, token, "invalid number");
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x4");
+        assert_eq!(error.to_string(), "<input>:2:3: invalid number: `x4`");
+        assert_eq!(error.with_file("input.txt").to_string(), "input.txt:2:3: invalid number: `x4`");
+    }
+
+    #[test]
+    fn reports_missing_text_at_end_of_input() {
+        let error = ParseError::at_end("ab\ncd", "missing program");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.to_string(), "<input>:2:3: missing program");
+    }
+
+    #[test]
+    fn moves_single_line_errors_to_their_line() {
+        let line = "7 x";
+        let error = ParseError::at(line, &line[2..], "invalid number").on_line(4);
+        assert_eq!(error.to_string(), "<input>:4:3: invalid number: `x`");
+    }
+
+    #[test]
+    fn parameter_errors_name_the_parameter_instead_of_a_place_in_the_input() {
+        let error = ParseError::in_param("width", "wide", "invalid usize").with_file("input.txt");
+        assert_eq!(error.param.as_deref(), Some("width"));
+        assert_eq!(error.to_string(), "parameter `width`: invalid usize: `wide`");
+    }
+}
@@ -0,0 +1,100 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const DARK_GRAY: Color = Color::rgb(60, 60, 60);
+    pub const GRAY: Color = Color::rgb(150, 150, 150);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 200, 80);
+    pub const BLUE: Color = Color::rgb(60, 110, 220);
+    pub const YELLOW: Color = Color::rgb(250, 210, 40);
+    pub const ORANGE: Color = Color::rgb(230, 130, 30);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+}
+
+/// A grid cell as drawn: terminals show the glyph, images fill the cell with the colour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+/// Drawn on top of a frame's cells, in the order they were added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Overlay {
+    /// A route through consecutive points, drawn as arrows.
+    Path { points: Vec<Point>, color: Color },
+    /// Cells marked with a background colour, keeping their glyphs.
+    Highlight { points: Vec<Point>, color: Color },
+    /// Text starting at a cell and running to the right.
+    Label { at: Point, text: String, color: Color },
+}
+
+/// One picture of a grid puzzle's state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub overlays: Vec<Overlay>,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Frame { cells, overlays: Vec::new() }
+    }
+
+    /// Draws every cell of `grid` with the cell `style` picks for it.
+    pub fn from_grid<T>(grid: &Grid<T>, style: impl FnMut(&T) -> Cell) -> Self {
+        Frame::new(grid.map(style))
+    }
+
+    pub fn with(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    pub fn path(self, points: Vec<Point>, color: Color) -> Self {
+        self.with(Overlay::Path { points, color })
+    }
+
+    pub fn highlight(self, points: Vec<Point>, color: Color) -> Self {
+        self.with(Overlay::Highlight { points, color })
+    }
+
+    pub fn label(self, at: Point, text: impl Into<String>, color: Color) -> Self {
+        self.with(Overlay::Label { at, text: text.into(), color })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles_grid_cells_and_keeps_overlay_order() {
+        let grid = Grid::parse("#.\n.#").unwrap();
+        let frame = Frame::from_grid(&grid, |&c| Cell::new(c, if c == '#' { Color::GRAY } else { Color::BLACK }))
+            .highlight(vec![Point::new(1, 0)], Color::BLUE)
+            .label(Point::ORIGIN, "hi", Color::WHITE);
+
+        assert_eq!(frame.cells[(1, 1)], Cell::new('#', Color::GRAY));
+        assert!(matches!(frame.overlays[..], [Overlay::Highlight { .. }, Overlay::Label { .. }]));
+    }
+}
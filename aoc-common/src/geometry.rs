@@ -0,0 +1,257 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on the puzzle plane: `x` is the column and `y` the row, growing downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Converts a `(row, col)` grid position.
+    pub fn from_position((row, col): (usize, usize)) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+
+    /// The `(row, col)` grid position, or `None` for negative coordinates.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Whether the point lies in the `width` x `height` rectangle anchored at the origin.
+    pub fn within(self, width: usize, height: usize) -> bool {
+        (0..width as i64).contains(&self.x) && (0..height as i64).contains(&self.y)
+    }
+
+    /// Rotates a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |direction| self.step(direction))
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .flat_map(|direction| [direction.delta(), direction.delta() + direction.turn_right().delta()])
+            .map(move |delta| self + delta)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    /// Offset as `(row, col)`.
+    pub fn movement(self) -> (isize, isize) {
+        let delta = self.delta();
+        (delta.y as isize, delta.x as isize)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_four_times_is_identity() {
+        for direction in Direction::ALL {
+            let mut turned = direction;
+            for _ in 0..4 {
+                turned = turned.turn_right();
+            }
+            assert_eq!(turned, direction);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().delta(), -direction.delta());
+        }
+    }
+
+    #[test]
+    fn parses_arrow_characters() {
+        assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('>'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_char(Direction::Left.to_char()), Some(Direction::Left));
+    }
+
+    #[test]
+    fn up_decreases_row() {
+        assert_eq!(Point::new(3, 5).step(Direction::Up), Point::new(3, 4));
+        assert_eq!(Direction::Up.movement(), (-1, 0));
+        assert_eq!(Direction::Right.movement(), (0, 1));
+        assert_eq!(Point::from_position((5, 3)), Point::new(3, 5));
+        assert_eq!(Point::new(3, 5).to_position(), Some((5, 3)));
+        assert_eq!(Point::new(-1, 5).to_position(), None);
+    }
+
+    #[test]
+    fn rotation_matches_turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.delta().rotate_right(), direction.turn_right().delta());
+            assert_eq!(direction.delta().rotate_left(), direction.turn_left().delta());
+        }
+    }
+
+    #[test]
+    fn vector_arithmetic_and_distance() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, -4));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.to_string(), "1,2");
+    }
+
+    #[test]
+    fn bounds_and_neighbors() {
+        assert!(Point::new(0, 0).within(2, 3));
+        assert!(Point::new(1, 2).within(2, 3));
+        assert!(!Point::new(2, 0).within(2, 3));
+        assert!(!Point::new(0, -1).within(2, 3));
+
+        assert_eq!(Point::ORIGIN.neighbors4().count(), 4);
+        let diagonals: Vec<Point> = Point::ORIGIN.neighbors8().filter(|p| p.x != 0 && p.y != 0).collect();
+        assert_eq!(diagonals.len(), 4);
+    }
+}
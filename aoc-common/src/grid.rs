@@ -0,0 +1,355 @@
+use crate::error::{ParseError, ParseResult};
+use crate::geometry::{Direction, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A `(row, col)` cell coordinate.
+pub type Position = (usize, usize);
+
+pub const ALL_DIRECTIONS: [(isize, isize); 8] =
+    [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> ParseResult<Self> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![fill; width * height], width, height }
+    }
+
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {}",
+            cells.len(),
+            width
+        );
+
+        let height = cells.len() / width;
+        Grid { cells, width, height }
+    }
+
+    /// Parses one row per non-empty line; every row must have the same length and
+    /// `parse_cell` returns `None` for characters that are not allowed.
+    pub fn parse_with(input: &str, mut parse_cell: impl FnMut(char) -> Option<T>) -> ParseResult<Self> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            for (offset, c) in line.char_indices() {
+                let cell = parse_cell(c).ok_or_else(|| {
+                    ParseError::at(input, &line[offset..offset + c.len_utf8()], "unexpected character")
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = line.chars().count();
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("row has {} cells, expected {}", row_width, width),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        point.within(self.width, self.height)
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        point.to_position().and_then(|position| self.get(position))
+    }
+
+    /// Flat index of a cell, or `None` when it lies outside the grid.
+    pub fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position).then(|| position.0 * self.width + position.1)
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        (index / self.width, index % self.width)
+    }
+
+    /// Moves `position` by `(row, col)` steps if the result is still inside the grid.
+    pub fn offset(&self, (row, col): Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        let target = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(target).then_some(target)
+    }
+
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.movement())
+    }
+
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(position, direction))
+    }
+
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS.into_iter().filter_map(move |delta| self.offset(position, delta))
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index / width, index % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.ray((0, col), (1, 0))
+    }
+
+    /// Cells from `start` down and to the right.
+    pub fn diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, (1, 1))
+    }
+
+    /// Cells from `start` down and to the left.
+    pub fn anti_diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, (1, -1))
+    }
+
+    /// Cells from `start` stepping by `delta` until the edge of the grid.
+    pub fn ray(&self, start: Position, delta: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.contains(start).then_some(start), move |&position| {
+            self.offset(position, delta)
+        })
+        .map(move |position| &self[position])
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells.iter().position(&mut predicate).map(|index| self.position_of(index))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        assert!(
+            self.contains((row, col)),
+            "({}, {}) is outside a {}x{} grid",
+            row,
+            col,
+            self.width,
+            self.height
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(
+            self.contains((row, col)),
+            "({}, {}) is outside a {}x{} grid",
+            row,
+            col,
+            self.width,
+            self.height
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point)
+            .unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        point
+            .to_position()
+            .and_then(|position| self.get_mut(position))
+            .unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rows_of_cells() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.row(1), ['c', 'd']);
+
+        let digits = Grid::parse_with("01\n23", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.cells(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_bad_cells() {
+        let error = Grid::parse("ab\nc").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "row has 1 cells, expected 2");
+
+        let error = Grid::parse_with("01\n2x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn indexes_checked_and_unchecked() {
+        let mut grid = Grid::parse("ab\ncd").unwrap();
+        assert_eq!(grid[(1, 0)], 'c');
+        assert_eq!(grid.get((0, 1)), Some(&'b'));
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get((2, 0)), None);
+
+        grid[(0, 0)] = 'z';
+        *grid.get_mut((1, 1)).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "zb\ncy");
+    }
+
+    #[test]
+    fn indexes_by_point() {
+        let mut grid = Grid::parse("ab\ncd").unwrap();
+        assert_eq!(grid[Point::new(0, 1)], 'c');
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert!(!grid.contains_point(Point::new(2, 0)));
+
+        grid[Point::new(1, 0)] = 'z';
+        assert_eq!(grid[(0, 1)], 'z');
+        assert_eq!(grid.step((0, 1), Direction::Left), Some((0, 0)));
+        assert_eq!(grid.step((0, 1), Direction::Up), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a 2x2 grid")]
+    fn unchecked_index_panics_out_of_bounds() {
+        let _ = Grid::parse("ab\ncd").unwrap()[(0, 2)];
+    }
+
+    #[test]
+    fn finds_matching_cells() {
+        let grid = Grid::parse("#.#\n#S.\n..S").unwrap();
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.position(|&c| c == 'E'), None);
+        assert_eq!(grid.find_all(|&c| c == 'S').collect::<Vec<_>>(), [(1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), [(0, 1), (1, 2), (2, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_diagonals() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def", "ghi"]);
+        assert_eq!(collect(&mut grid.column(1)), "beh");
+        assert_eq!(collect(&mut grid.diagonal((0, 0))), "aei");
+        assert_eq!(collect(&mut grid.anti_diagonal((0, 2))), "ceg");
+        assert_eq!(collect(&mut grid.ray((2, 2), (0, -1))), "ihg");
+        assert_eq!(collect(&mut grid.ray((3, 0), (0, 1))), "");
+    }
+
+    #[test]
+    fn maps_cells_and_keeps_shape() {
+        let grid = Grid::from_vec(2, vec![1, 2, 3, 4]).map(|n| n * 10);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.to_string(), "1020\n3040");
+    }
+}
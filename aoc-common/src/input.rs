@@ -0,0 +1,187 @@
+use memmap2::Mmap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+pub fn read_lines(path: impl AsRef<Path>) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    let file = File::open(path)?;
+    Ok(BufReader::new(file).lines())
+}
+
+pub type Lines = Box<dyn Iterator<Item = io::Result<String>>>;
+
+/// Where a puzzle input comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    /// Standard input, written `-` on the command line.
+    Stdin,
+    /// A file mapped into memory instead of read, for generated inputs too big to copy.
+    Mapped(PathBuf),
+}
+
+impl InputSource {
+    /// `-` for standard input, anything else a path; `map` maps the file into memory.
+    pub fn from_arg(arg: &Path, map: bool) -> Result<Self, String> {
+        match (arg == Path::new("-"), map) {
+            (true, true) => Err("standard input cannot be memory-mapped".to_string()),
+            (true, false) => Ok(InputSource::Stdin),
+            (false, true) => Ok(InputSource::Mapped(arg.to_path_buf())),
+            (false, false) => Ok(InputSource::Path(arg.to_path_buf())),
+        }
+    }
+
+    /// The source a day binary's arguments name: `[--mmap] [PATH | -]`, where no path
+    /// means the default input of the day crate at `day_dir`, see [`default_input`].
+    pub fn from_args(args: impl IntoIterator<Item = String>, day_dir: &Path) -> Result<Self, String> {
+        let mut map = false;
+        let mut path = None;
+        for arg in args {
+            match arg.as_str() {
+                "--mmap" => map = true,
+                _ if path.is_none() => path = Some(PathBuf::from(arg)),
+                _ => return Err(format!("unexpected argument `{}`; usage: [--mmap] [PATH | -]", arg)),
+            }
+        }
+
+        InputSource::from_arg(&path.unwrap_or_else(|| default_input(day_dir)), map)
+    }
+
+    /// The whole input as text.
+    pub fn read(&self) -> io::Result<InputText> {
+        match self {
+            InputSource::Path(path) => read_input(path).map(InputText::Owned),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().lock().read_to_string(&mut text)?;
+                Ok(InputText::Owned(text))
+            }
+            InputSource::Mapped(path) => {
+                // SAFETY: the map is only read, and the input file is not expected to
+                // change while a day runs; a truncated file would fault rather than
+                // expose freed memory.
+                let map = unsafe { Mmap::map(&File::open(path)?)? };
+                std::str::from_utf8(&map).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+                Ok(InputText::Mapped(map))
+            }
+        }
+    }
+
+    /// The input a line at a time, holding only the line being read.
+    pub fn lines(&self) -> io::Result<Lines> {
+        match self {
+            InputSource::Path(path) | InputSource::Mapped(path) => {
+                Ok(Box::new(BufReader::new(File::open(path)?).lines()))
+            }
+            InputSource::Stdin => Ok(Box::new(io::stdin().lines())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) | InputSource::Mapped(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// A day's own `input.txt` if it has one, otherwise where `aoc fetch` caches the
+/// download: `inputs/<year>/day-<n>/input.txt` in the workspace holding `day_dir`.
+pub fn default_input(day_dir: &Path) -> PathBuf {
+    let local = day_dir.join("input.txt");
+    let year_dir = day_dir.parent();
+    match (year_dir.and_then(Path::parent), year_dir.and_then(Path::file_name), day_dir.file_name()) {
+        (Some(root), Some(year), Some(day)) if !local.exists() => {
+            root.join("inputs").join(year).join(day).join("input.txt")
+        }
+        _ => local,
+    }
+}
+
+/// Input text, read into memory or mapped from its file.
+pub enum InputText {
+    Owned(String),
+    Mapped(Mmap),
+}
+
+impl Deref for InputText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            InputText::Owned(text) => text,
+            // SAFETY: `InputSource::read` checked that the mapped bytes are UTF-8.
+            InputText::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-common-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn reads_whole_file_and_lines() {
+        let path = temp_path("input.txt");
+        fs::write(&path, "1 2\n3 4\n").unwrap();
+
+        assert_eq!(read_input(&path).unwrap(), "1 2\n3 4\n");
+        let lines: Vec<String> = read_lines(&path).unwrap().map(Result::unwrap).collect();
+        assert_eq!(lines, ["1 2", "3 4"]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        assert!(read_input("this/file/does/not/exist.txt").is_err());
+    }
+
+    #[test]
+    fn sources_read_files_whole_mapped_or_by_line() {
+        let path = temp_path("source.txt");
+        fs::write(&path, "5 6\n7 8\n").unwrap();
+
+        for source in [InputSource::Path(path.clone()), InputSource::Mapped(path.clone())] {
+            assert_eq!(&*source.read().unwrap(), "5 6\n7 8\n");
+            let lines: Vec<String> = source.lines().unwrap().map(Result::unwrap).collect();
+            assert_eq!(lines, ["5 6", "7 8"]);
+        }
+
+        fs::write(&path, [0xff, b'\n']).unwrap();
+        assert!(InputSource::Mapped(path.clone()).read().is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn arguments_name_a_file_stdin_or_the_default_input() {
+        let day_dir = temp_path("workspace").join("2024").join("day-3");
+        let args = |args: &[&str]| InputSource::from_args(args.iter().map(|arg| arg.to_string()), &day_dir);
+
+        assert_eq!(args(&["-"]), Ok(InputSource::Stdin));
+        assert_eq!(args(&["--mmap", "big.txt"]), Ok(InputSource::Mapped(PathBuf::from("big.txt"))));
+        assert!(args(&["--mmap", "-"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+
+        let cached = temp_path("workspace").join("inputs").join("2024").join("day-3").join("input.txt");
+        assert_eq!(args(&[]), Ok(InputSource::Path(cached)));
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("input.txt"), "").unwrap();
+        assert_eq!(args(&[]), Ok(InputSource::Path(day_dir.join("input.txt"))));
+
+        fs::remove_dir_all(temp_path("workspace")).unwrap();
+    }
+}
@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod error;
+pub mod frame;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod rng;
+pub mod search;
+pub mod solution;
+pub mod timing;
+
+pub use answer::Answer;
+pub use error::{ParseError, ParseResult};
+pub use params::Params;
+pub use solution::Solution;
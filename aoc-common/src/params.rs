@@ -0,0 +1,71 @@
+use crate::error::{ParseError, ParseResult};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Named puzzle parameters, for values such as grid sizes that differ between
+/// the examples and the real input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// The value of `name` parsed as `T`, or `default` when it is not set.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> ParseResult<T> {
+        match self.values.get(name) {
+            Some(value) => value
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::in_param(name, value, format!("invalid {}", std::any::type_name::<T>()))),
+            None => Ok(default),
+        }
+    }
+
+    /// Like [`Params::get`], for sizes that must be at least 1.
+    pub fn get_positive(&self, name: &str, default: usize) -> ParseResult<usize> {
+        match self.get(name, default)? {
+            0 => Err(ParseError::in_param(name, "0", "must be at least 1")),
+            value => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let params = Params::new().with("width", "11");
+
+        assert_eq!(params.get("width", 101), Ok(11));
+        assert_eq!(params.get("height", 103), Ok(103));
+        assert_eq!(params.iter().collect::<Vec<_>>(), [("width", "11")]);
+    }
+
+    #[test]
+    fn reports_invalid_values_by_name() {
+        let error = Params::new().with("width", "wide").get::<usize>("width", 101).unwrap_err();
+        assert_eq!(error.to_string(), "parameter `width`: invalid usize: `wide`");
+
+        let error = Params::new().with("width", "0").get_positive("width", 101).unwrap_err();
+        assert_eq!(error.to_string(), "parameter `width`: must be at least 1: `0`");
+    }
+}
@@ -0,0 +1,202 @@
+use crate::error::{ParseError, ParseResult};
+use std::io;
+use std::str::FromStr;
+
+/// Parses `token`, a slice of `input`, reporting failures at its position.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> ParseResult<T> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, format!("invalid {}", std::any::type_name::<T>())))
+}
+
+/// Lines that contain more than whitespace.
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// Parses each line from `lines` that contains more than whitespace as it arrives,
+/// so the whole input is never held at once; errors point at their line of the input.
+pub fn each_line<T>(
+    lines: &mut dyn Iterator<Item = io::Result<String>>,
+    mut parse_line: impl FnMut(&str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    let mut items = Vec::new();
+    for (index, line) in lines.enumerate() {
+        let line = line.map_err(|err| ParseError::unreadable(index + 1, err))?;
+        if !line.trim().is_empty() {
+            items.push(parse_line(&line).map_err(|err| err.on_line(index + 1))?);
+        }
+    }
+
+    Ok(items)
+}
+
+/// The whitespace-separated integers in `text`; any other token is an error.
+pub fn ints<T: FromStr>(input: &str, text: &str) -> ParseResult<Vec<T>> {
+    text.split_whitespace().map(|token| parse_token(input, token)).collect()
+}
+
+/// The integers in `text` between each `separator`, with optional whitespace around them.
+pub fn separated_ints<T: FromStr>(input: &str, text: &str, separator: char) -> ParseResult<Vec<T>> {
+    text.split(separator).map(|token| parse_token(input, token.trim())).collect()
+}
+
+/// The `N` integers standing in for the `{}`s of `pattern`, which the rest of
+/// `text` must match exactly: `int_pattern(input, line, "p={},{} v={},{}")`.
+pub fn int_pattern<T: FromStr, const N: usize>(input: &str, text: &str, pattern: &str) -> ParseResult<[T; N]> {
+    assert_eq!(pattern.matches("{}").count(), N, "pattern `{}` must hold {} numbers", pattern, N);
+    let expected = |rest: &str| ParseError::at(input, rest, format!("expected `{}`", pattern));
+
+    let mut numbers = Vec::with_capacity(N);
+    let mut rest = text;
+    for (index, literal) in pattern.split("{}").enumerate() {
+        if index > 0 {
+            // The token runs to the next punctuation, so `54o0` is reported whole.
+            let sign = usize::from(rest.starts_with('-'));
+            let end = rest[sign..].find(|c: char| !c.is_alphanumeric()).map_or(rest.len(), |end| sign + end);
+            numbers.push(parse_token(input, &rest[..end])?);
+            rest = &rest[end..];
+        }
+        rest = rest.strip_prefix(literal).ok_or_else(|| expected(rest))?;
+    }
+    if !rest.is_empty() {
+        return Err(expected(rest));
+    }
+
+    Ok(numbers.try_into().unwrap_or_else(|_| unreachable!("one number per `{{}}`")))
+}
+
+/// Exactly `N` integers from `text`, see [`ints`].
+pub fn int_array<T: FromStr, const N: usize>(input: &str, text: &str) -> ParseResult<[T; N]> {
+    let numbers = ints(input, text)?;
+    let found = numbers.len();
+
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::at(input, text, format!("expected {} numbers, found {}", N, found)))
+}
+
+/// Groups of consecutive non-blank lines, as slices of `input`.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut offset = 0;
+    let mut rows = input.split_inclusive('\n').map(move |row| {
+        let start = offset;
+        offset += row.len();
+        (start, row.trim_end_matches(['\n', '\r']))
+    });
+
+    std::iter::from_fn(move || {
+        let (start, first) = rows.by_ref().find(|(_, row)| !row.trim().is_empty())?;
+        let mut end = start + first.len();
+        for (row_start, row) in rows.by_ref() {
+            if row.trim().is_empty() {
+                break;
+            }
+            end = row_start + row.len();
+        }
+
+        Some(&input[start..end])
+    })
+}
+
+/// Exactly `N` blank-line separated blocks, see [`blocks`].
+pub fn block_array<const N: usize>(input: &str) -> ParseResult<[&str; N]> {
+    let blocks: Vec<&str> = blocks(input).collect();
+    let found = blocks.len();
+
+    blocks.try_into().map_err(|_| {
+        ParseError::at_end(input, format!("expected {} blank-line separated sections, found {}", N, found))
+    })
+}
+
+/// Splits a `key: value` line.
+pub fn key_value<'a>(input: &str, line: &'a str) -> ParseResult<(&'a str, &'a str)> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| ParseError::at(input, line, "expected `key: value`"))
+}
+
+/// The value of a `key: value` line whose key must be `key`.
+pub fn field<'a>(input: &str, line: &'a str, key: &str) -> ParseResult<&'a str> {
+    match key_value(input, line)? {
+        (found, value) if found == key => Ok(value),
+        _ => Err(ParseError::at(input, line, format!("expected `{}: ...`", key))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tokens_in_place() {
+        let input = "12,-3,z";
+        let tokens: Vec<&str> = input.split(',').collect();
+
+        assert_eq!(parse_token::<i32>(input, tokens[1]), Ok(-3));
+        let error = parse_token::<i32>(input, tokens[2]).unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (1, 7, "invalid i32"));
+    }
+
+    #[test]
+    fn parses_streamed_lines_one_at_a_time() {
+        let mut lines = ["1 2", "", "3 4"].into_iter().map(|line| Ok(line.to_string()));
+        assert_eq!(each_line(&mut lines, |line| ints::<i32>(line, line)), Ok(vec![vec![1, 2], vec![3, 4]]));
+
+        let mut lines = ["1 2", "", "3 300"].into_iter().map(|line| Ok(line.to_string()));
+        let error = each_line(&mut lines, |line| ints::<u8>(line, line)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 3, "300"));
+    }
+
+    #[test]
+    fn parses_integers_and_nothing_else() {
+        assert_eq!(ints::<i32>("7 -6  4\t2", "7 -6  4\t2"), Ok(vec![7, -6, 4, 2]));
+        let input = "7 6 4 x 2 1";
+        let error = ints::<i32>(input, input).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (7, "x"));
+        assert_eq!(int_array::<i64, 2>("94   34", "94   34"), Ok([94, 34]));
+        assert_eq!(separated_ints::<u8>("75, 47,61", "75, 47,61", ','), Ok(vec![75, 47, 61]));
+        assert!(separated_ints::<u8>("75,,61", "75,,61", ',').is_err());
+
+        let line = "p=0,4 v=3,-3";
+        assert_eq!(int_pattern::<i32, 4>(line, line, "p={},{} v={},{}"), Ok([0, 4, 3, -3]));
+        let line = "p=0,4 w=3,-3";
+        let error = int_pattern::<i32, 4>(line, line, "p={},{} v={},{}").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (6, "expected `p={},{} v={},{}`"));
+        let line = "Prize: X=8400, Y=54o0";
+        let error = int_pattern::<i64, 2>(line, line, "Prize: X={}, Y={}").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (18, "54o0"));
+        assert!(int_pattern::<i64, 2>("1,2 3", "1,2 3", "{},{}").is_err());
+
+        let error = int_array::<i64, 3>("1 2", "1 2").unwrap_err();
+        assert_eq!(error.message, "expected 3 numbers, found 2");
+
+        let input = "1\n300 2";
+        let error = ints::<u8>(input, &input[2..]).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "300"));
+    }
+
+    #[test]
+    fn splits_blank_line_separated_blocks() {
+        let input = "\na\nb\n\n\n c \r\n\r\nd";
+        let found: Vec<&str> = blocks(input).collect();
+        assert_eq!(found, ["a\nb", " c ", "d"]);
+
+        let [first, second] = block_array::<2>("x\n\ny\n").unwrap();
+        assert_eq!((first, second), ("x", "y"));
+
+        let error = block_array::<2>("x\ny\n").unwrap_err();
+        assert_eq!(error.message, "expected 2 blank-line separated sections, found 1");
+    }
+
+    #[test]
+    fn reads_key_value_headers() {
+        let input = "Register A: 729\nProgram 0";
+        let mut lines = input.lines();
+
+        assert_eq!(field(input, lines.next().unwrap(), "Register A"), Ok("729"));
+        let error = key_value(input, lines.next().unwrap()).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(field(input, "Register B: 1", "Register A").is_err());
+    }
+}
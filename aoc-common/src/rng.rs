@@ -0,0 +1,87 @@
+/// A small seeded random number generator (SplitMix64). Its sequence never
+/// changes between builds or platforms, so a seed always gives the same input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`; `bound` must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// A value in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        let span = high.abs_diff(low);
+        match span.checked_add(1) {
+            Some(count) => low.wrapping_add(self.below(count) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// An index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True `numerator` times out of `denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.index(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_give_fixed_sequences() {
+        let mut rng = Rng::new(1);
+        assert_eq!(rng.next_u64(), 0x910a_2dec_8902_5cc1);
+        assert_eq!(rng.next_u64(), 0xbeeb_8da1_658e_ec67);
+
+        let first: Vec<_> = (0..5).map(|_| Rng::new(7).between(1, 6)).collect();
+        assert!(first.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn stays_within_ranges_and_shuffles_without_losing_items() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.between(-3, 3)));
+            assert!(rng.index(5) < 5);
+        }
+        assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
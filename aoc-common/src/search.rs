@@ -0,0 +1,261 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Anything that can list the weighted edges leaving a node.
+///
+/// Closures `Fn(&N) -> impl IntoIterator<Item = (N, usize)>` implement it, so a
+/// puzzle usually only has to describe its moves.
+pub trait Graph<N> {
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = (N, usize)>;
+}
+
+impl<N, F, I> Graph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    fn neighbors(&self, node: &N) -> impl IntoIterator<Item = (N, usize)> {
+        self(node)
+    }
+}
+
+/// The outcome of a search: the cost of every reached node and all of its
+/// predecessors on a cheapest path from the start.
+pub struct Search<N> {
+    start: N,
+    costs: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    fn new(start: N) -> Self {
+        Search { start, costs: HashMap::from([(start, 0)]), predecessors: HashMap::new(), goals: Vec::new() }
+    }
+
+    /// Records an edge into `node`; returns whether it improved the best known cost.
+    fn relax(&mut self, from: N, node: N, cost: usize) -> bool {
+        match self.costs.get(&node) {
+            Some(&best) if cost > best => false,
+            Some(&best) if cost == best => {
+                let predecessors = self.predecessors.entry(node).or_default();
+                if !predecessors.contains(&from) {
+                    predecessors.push(from);
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(node, cost);
+                self.predecessors.insert(node, vec![from]);
+                true
+            }
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every goal reached at the lowest cost, in the order they were settled.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.costs.keys()
+    }
+
+    /// One cheapest path from the start to `node`, both ends included.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        self.cost(&node)?;
+
+        let mut path = vec![node];
+        let mut current = node;
+        while current != self.start {
+            current = self.predecessors[&current][0];
+            path.push(current);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every node lying on some cheapest path from the start to one of `targets`.
+    pub fn nodes_on_shortest_paths(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut stack: Vec<N> = targets.into_iter().filter(|target| self.costs.contains_key(target)).collect();
+        let mut nodes = HashSet::new();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node) {
+                stack.extend(self.predecessors.get(&node).into_iter().flatten().copied());
+            }
+        }
+
+        nodes
+    }
+
+    /// Number of distinct cheapest paths from the start to `node`.
+    pub fn path_count(&self, node: N) -> usize {
+        fn count<N: Copy + Eq + Hash>(search: &Search<N>, node: N, memo: &mut HashMap<N, usize>) -> usize {
+            if node == search.start {
+                return 1;
+            }
+            if let Some(&cached) = memo.get(&node) {
+                return cached;
+            }
+
+            let paths = search
+                .predecessors
+                .get(&node)
+                .into_iter()
+                .flatten()
+                .map(|&previous| count(search, previous, memo))
+                .sum();
+            memo.insert(node, paths);
+            paths
+        }
+
+        if self.costs.contains_key(&node) {
+            count(self, node, &mut HashMap::new())
+        } else {
+            0
+        }
+    }
+}
+
+/// Breadth-first search counting every edge as one step, whatever its weight.
+///
+/// Stops once all goals at the shallowest goal depth are found; pass `|_| false`
+/// to explore everything reachable.
+pub fn bfs<N, G>(graph: &G, start: N, mut is_goal: impl FnMut(&N) -> bool) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    G: Graph<N>,
+{
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let depth = search.costs[&node];
+        if search.goal_cost().is_some_and(|goal_depth| depth > goal_depth) {
+            break;
+        }
+        if is_goal(&node) {
+            search.goals.push(node);
+            continue;
+        }
+
+        for (next, _) in graph.neighbors(&node) {
+            if search.relax(node, next, depth + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+pub fn dijkstra<N, G>(graph: &G, start: N, is_goal: impl FnMut(&N) -> bool) -> Search<N>
+where
+    N: Copy + Eq + Hash + Ord,
+    G: Graph<N>,
+{
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// A* search; `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, G>(
+    graph: &G,
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> usize,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash + Ord,
+    G: Graph<N>,
+{
+    let mut search = Search::new(start);
+    let mut settled = HashSet::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((estimate, cost, node))) = heap.pop() {
+        if search.goal_cost().is_some_and(|goal_cost| estimate > goal_cost) {
+            break;
+        }
+        if cost > search.costs[&node] || !settled.insert(node) {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goals.push(node);
+            continue;
+        }
+
+        for (next, weight) in graph.neighbors(&node) {
+            let next_cost = cost + weight;
+            if search.relax(node, next, next_cost) {
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 3
+    // 0 -1-> 2 -1-> 3
+    // 0 -5-> 3
+    fn diamond(node: &u32) -> Vec<(u32, usize)> {
+        match node {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_edges_not_weights() {
+        let search = bfs(&diamond, 0, |&node| node == 3);
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.path(3), Some(vec![0, 3]));
+    }
+
+    #[test]
+    fn dijkstra_tracks_every_cheapest_predecessor() {
+        let search = dijkstra(&diamond, 0, |&node| node == 3);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.goals(), [3]);
+        assert_eq!(search.path_count(3), 2);
+        assert_eq!(search.nodes_on_shortest_paths([3]), HashSet::from([0, 1, 2, 3]));
+
+        let path = search.path(3).unwrap();
+        assert!(path == [0, 1, 3] || path == [0, 2, 3]);
+    }
+
+    #[test]
+    fn unreachable_goals_have_no_cost() {
+        let search = dijkstra(&diamond, 1, |&node| node == 0);
+        assert_eq!(search.goal_cost(), None);
+        assert_eq!(search.path(0), None);
+        assert_eq!(search.path_count(0), 0);
+        assert_eq!(search.reached().count(), 2);
+    }
+
+    #[test]
+    fn astar_finds_the_cheapest_route_on_a_line() {
+        let line = |&node: &i32| [(node - 1, 1), (node + 1, 1)];
+        let search = astar(&line, 0, |&node| node == 7, |&node| (7 - node).unsigned_abs() as usize);
+
+        assert_eq!(search.goal_cost(), Some(7));
+        assert_eq!(search.path(7), Some((0..=7).collect()));
+        assert!(search.reached().all(|&node| node >= -1));
+    }
+}
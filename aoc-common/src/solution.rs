@@ -0,0 +1,191 @@
+use crate::frame::Frame;
+use crate::input::InputSource;
+use crate::rng::Rng;
+use crate::{Answer, ParseError, Params, ParseResult};
+use std::io;
+use std::path::Path;
+use std::{env, process};
+
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    type Input;
+
+    /// Names of the parameters `parse_with` reads; the runner rejects any other.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+
+    /// Parses with puzzle parameters; days whose examples use different sizes or
+    /// step counts than the real input read them here.
+    fn parse_with(input: &str, params: &Params) -> ParseResult<Self::Input> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    /// Whether [`Solution::parse_lines`] parses each line as it is read; days whose
+    /// format spans lines leave it off and are handed the whole text.
+    const LINE_ORIENTED: bool = false;
+
+    /// Parses the input from its lines. Line-oriented days override this to never
+    /// hold the whole input; the default gathers the lines and parses the text.
+    fn parse_lines(lines: &mut dyn Iterator<Item = io::Result<String>>, params: &Params) -> ParseResult<Self::Input> {
+        let mut input = String::new();
+        for (index, line) in lines.enumerate() {
+            let line = line.map_err(|err| ParseError::unreadable(index + 1, err))?;
+            input.push_str(&line);
+            input.push('\n');
+        }
+        Self::parse_with(&input, params)
+    }
+
+    /// Reads and parses `source`: line by line for line-oriented days, unless the
+    /// file is mapped, otherwise as one text. Errors name the source.
+    fn parse_source(source: &InputSource, params: &Params) -> Result<Self::Input, String> {
+        let read_error = |err: io::Error| format!("Failed to read {}: {}", source, err);
+        let parsed = if Self::LINE_ORIENTED && !matches!(source, InputSource::Mapped(_)) {
+            Self::parse_lines(&mut source.lines().map_err(read_error)?, params)
+        } else {
+            Self::parse_with(&source.read().map_err(read_error)?, params)
+        };
+
+        parsed.map_err(|err| err.with_file(source.to_string()).to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Named intermediate values worth reporting next to the answers.
+    fn diagnostics(input: &Self::Input) -> Vec<(&'static str, Answer)> {
+        let _ = input;
+        Vec::new()
+    }
+
+    /// Hands frames showing the solution at work to `emit`; days without a
+    /// visualization emit none.
+    fn visualize(input: &Self::Input, emit: &mut dyn FnMut(Frame)) {
+        let _ = (input, emit);
+    }
+
+    /// A slow but plainly correct answer to `part`, for checking the optimized
+    /// solver against; `None` for parts without a reference.
+    fn reference(input: &Self::Input, part: u8) -> Option<Answer> {
+        let _ = (input, part);
+        None
+    }
+
+    /// A random, valid puzzle input; what `size` counts (lines, grid side, ...)
+    /// depends on the day. Days without a generator return `None`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let _ = (rng, size);
+        None
+    }
+
+    /// The answer to `part`, which must be 1 or 2.
+    fn answer(input: &Self::Input, part: u8) -> Answer {
+        match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => panic!("day {} has no part {}, only 1 and 2", Self::DAY, part),
+        }
+    }
+
+    fn solve(input: &str, part: u8) -> ParseResult<Answer> {
+        Self::solve_with(input, part, &Params::default())
+    }
+
+    fn solve_with(input: &str, part: u8, params: &Params) -> ParseResult<Answer> {
+        let input = Self::parse_with(input, params)?;
+        Ok(Self::answer(&input, part))
+    }
+}
+
+/// The body of a day binary: solves the input its arguments name, by default the
+/// input of the day crate at `day_dir`, and prints both parts.
+pub fn run_main<S: Solution>(day_dir: &str) {
+    let input = InputSource::from_args(env::args().skip(1), Path::new(day_dir))
+        .and_then(|source| S::parse_source(&source, &Params::default()))
+        .unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1)
+        });
+
+    println!("Part 1: {}", S::part1(&input));
+    println!("Part 2: {}", S::part2(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_token;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 0;
+
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            input.split(',').map(|n| parse_token(input, n)).collect()
+        }
+
+        fn parse_with(input: &str, params: &Params) -> ParseResult<Self::Input> {
+            params.get("scale", 1_i64)?;
+            Self::parse(input)
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.clone().into()
+        }
+    }
+
+    #[test]
+    fn solve_dispatches_to_selected_part() {
+        assert_eq!(Sum::solve("1,2,3", 1), Ok(Answer::Integer(6)));
+        assert_eq!(Sum::solve("1,2,3", 2), Ok(Answer::List(vec![1, 2, 3])));
+    }
+
+    #[test]
+    #[should_panic(expected = "day 0 has no part 3, only 1 and 2")]
+    fn solve_rejects_other_parts() {
+        let _ = Sum::solve("1,2,3", 3);
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        let error = Sum::solve("1,x,3", 1).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "x"));
+    }
+
+    #[test]
+    fn lines_are_gathered_for_days_that_parse_whole_text() {
+        let mut lines = ["1,2", "3"].into_iter().map(|line| Ok(line.to_string()));
+        let error = Sum::parse_lines(&mut lines, &Params::default()).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "2\n3\n"));
+
+        let missing = InputSource::Path("this/file/does/not/exist.txt".into());
+        let error = Sum::parse_source(&missing, &Params::default()).unwrap_err();
+        assert!(error.starts_with("Failed to read this/file/does/not/exist.txt: "), "{}", error);
+    }
+
+    #[test]
+    fn parameter_errors_name_the_parameter_not_the_input() {
+        let path = std::env::temp_dir().join(format!("aoc-common-sum-{}.txt", std::process::id()));
+        std::fs::write(&path, "1,2").unwrap();
+        let source = InputSource::Path(path.clone());
+
+        let error = Sum::parse_source(&source, &Params::new().with("scale", "big")).unwrap_err();
+        assert_eq!(error, "parameter `scale`: invalid i64: `big`");
+        let error = Sum::parse_source(&source, &Params::new().with("scale", "2")).map(|_| ());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(error, Ok(()));
+    }
+}
@@ -0,0 +1,92 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+        samples.sort_unstable();
+
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Stats {
+            samples: samples.len(),
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Runs `f` up to `iterations` times, stopping early once `budget` is spent.
+/// At least one sample is always taken.
+pub fn measure<T>(iterations: usize, budget: Duration, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(iterations.max(1));
+
+    while samples.is_empty() || (samples.len() < iterations && start.elapsed() < budget) {
+        let (result, elapsed) = timed(&mut f);
+        black_box(result);
+        samples.push(elapsed);
+    }
+
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_result_and_elapsed_time() {
+        let (result, elapsed) = timed(|| {
+            std::thread::sleep(Duration::from_millis(5));
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert!(elapsed >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn single_sample_is_every_statistic() {
+        let stats = Stats::from_samples(vec![Duration::from_micros(7)]);
+
+        assert_eq!(stats.min, stats.median);
+        assert_eq!(stats.median, stats.p95);
+    }
+
+    #[test]
+    fn measure_respects_iteration_count_and_budget() {
+        let mut calls = 0;
+        let stats = measure(25, Duration::from_secs(60), || calls += 1);
+        assert_eq!((stats.samples, calls), (25, 25));
+
+        let stats = measure(1000, Duration::ZERO, || ());
+        assert_eq!(stats.samples, 1);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::raster::rasterize;
+use crate::Renderer;
+use aoc_common::frame::Frame;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Palette quantization effort, from 1 (best colours) to 30 (fastest); the
+/// frames use a handful of flat colours, so the fastest setting loses nothing.
+const SPEED: i32 = 30;
+
+/// Writes every frame into one looping animated GIF, created with the first frame.
+pub struct GifRenderer {
+    path: PathBuf,
+    encoder: Option<GifEncoder<BufWriter<File>>>,
+    scale: u32,
+    delay: Duration,
+}
+
+impl GifRenderer {
+    pub fn new(path: &Path, scale: u32, delay: Duration) -> Self {
+        GifRenderer { path: path.to_path_buf(), encoder: None, scale, delay }
+    }
+
+    fn create(path: &Path) -> Result<GifEncoder<BufWriter<File>>, String> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+        }
+        let file = File::create(path).map_err(|err| format!("Failed to create {}: {}", path.display(), err))?;
+
+        let mut encoder = GifEncoder::new_with_speed(BufWriter::new(file), SPEED);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+        Ok(encoder)
+    }
+}
+
+impl Renderer for GifRenderer {
+    fn render(&mut self, frame: &Frame) -> Result<(), String> {
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => self.encoder.insert(GifRenderer::create(&self.path)?),
+        };
+        let image = DynamicImage::ImageRgb8(rasterize(frame, self.scale)).into_rgba8();
+        let frame = image::Frame::from_parts(image, 0, 0, Delay::from_saturating_duration(self.delay));
+
+        encoder.encode_frame(frame).map_err(|err| format!("Failed to write {}: {}", self.path.display(), err))
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        // The encoder writes the GIF trailer when it is dropped.
+        drop(self.encoder.take());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::{Cell, Color};
+    use aoc_common::grid::Grid;
+    use image::codecs::gif::GifDecoder;
+    use image::AnimationDecoder;
+    use std::env;
+    use std::io::BufReader;
+
+    #[test]
+    fn writes_one_animation_frame_per_frame() {
+        let path = env::temp_dir().join(format!("aoc-viz-{}.gif", std::process::id()));
+        let grid = Grid::parse("#.\n.#").unwrap();
+
+        let mut renderer = GifRenderer::new(&path, 3, Duration::from_millis(50));
+        for color in [Color::RED, Color::GREEN, Color::BLUE] {
+            renderer.render(&Frame::from_grid(&grid, |&c| Cell::new(c, color))).unwrap();
+        }
+        renderer.finish().unwrap();
+
+        let decoder = GifDecoder::new(BufReader::new(File::open(&path).unwrap())).unwrap();
+        let frames = decoder.into_frames().collect_frames().unwrap();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[1].buffer().dimensions(), (6, 6));
+        assert_eq!(frames[1].buffer().get_pixel(0, 0).0, [80, 200, 80, 255]);
+
+        fs::remove_file(path).unwrap();
+    }
+}
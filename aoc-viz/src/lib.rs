@@ -0,0 +1,98 @@
+pub mod font;
+pub mod gif;
+pub mod png;
+pub mod raster;
+pub mod terminal;
+
+use aoc_common::frame::{Color, Frame, Overlay};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+
+pub use gif::GifRenderer;
+pub use png::PngRenderer;
+pub use terminal::TerminalRenderer;
+
+/// A backend that draws a sequence of frames.
+pub trait Renderer {
+    fn render(&mut self, frame: &Frame) -> Result<(), String>;
+
+    /// Completes the output once the last frame has been drawn.
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// A cell with the path and highlight overlays applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Drawn {
+    pub glyph: char,
+    pub color: Color,
+    pub background: Option<Color>,
+}
+
+impl Drawn {
+    /// The colour that fills the cell in an image.
+    pub fn fill(&self) -> Color {
+        self.background.unwrap_or(self.color)
+    }
+}
+
+/// The glyph for the step from `from` to `to`, if they are neighbours.
+fn arrow(from: Point, to: Point) -> Option<char> {
+    Direction::ALL.into_iter().find(|direction| from.step(*direction) == to).map(Direction::to_char)
+}
+
+/// Applies highlights and paths to the frame's cells; labels are left to the
+/// backends, which draw text differently.
+pub fn compose(frame: &Frame) -> Grid<Drawn> {
+    let mut drawn = frame.cells.map(|cell| Drawn { glyph: cell.glyph, color: cell.color, background: None });
+
+    for overlay in &frame.overlays {
+        match overlay {
+            Overlay::Highlight { points, color } => {
+                for &point in points {
+                    if let Some(cell) = point.to_position().and_then(|position| drawn.get_mut(position)) {
+                        cell.background = Some(*color);
+                    }
+                }
+            }
+            Overlay::Path { points, color } => {
+                for (index, &point) in points.iter().enumerate() {
+                    let glyph = points
+                        .get(index + 1)
+                        .and_then(|&next| arrow(point, next))
+                        .or_else(|| index.checked_sub(1).and_then(|previous| arrow(points[previous], point)))
+                        .unwrap_or('*');
+                    if let Some(cell) = point.to_position().and_then(|position| drawn.get_mut(position)) {
+                        *cell = Drawn { glyph, color: *color, background: None };
+                    }
+                }
+            }
+            Overlay::Label { .. } => {}
+        }
+    }
+
+    drawn
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Cell;
+
+    #[test]
+    fn paths_draw_arrows_over_highlights() {
+        let grid = Grid::parse("...\n...").unwrap();
+        let frame = Frame::from_grid(&grid, |&c| Cell::new(c, Color::GRAY))
+            .highlight(vec![Point::new(0, 0), Point::new(2, 1)], Color::BLUE)
+            .path(vec![Point::new(0, 0), Point::new(1, 0), Point::new(1, 1), Point::new(5, 5)], Color::GREEN);
+
+        let drawn = compose(&frame);
+        let glyphs: String = drawn.cells().iter().map(|cell| cell.glyph).collect();
+        assert_eq!(glyphs, ">v..v.");
+        assert_eq!(drawn[(0, 0)].fill(), Color::GREEN);
+        assert_eq!(drawn[(1, 1)].glyph, 'v');
+        assert_eq!(drawn[(1, 2)].fill(), Color::BLUE);
+        assert_eq!(drawn[(1, 2)].glyph, '.');
+    }
+}
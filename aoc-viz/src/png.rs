@@ -0,0 +1,33 @@
+use crate::raster::rasterize;
+use crate::Renderer;
+use aoc_common::frame::Frame;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Writes each frame to `<dir>/frame_NNNNN.png`, creating `dir` with the first one.
+pub struct PngRenderer {
+    dir: PathBuf,
+    scale: u32,
+    count: usize,
+}
+
+impl PngRenderer {
+    pub fn new(dir: &Path, scale: u32) -> Self {
+        PngRenderer { dir: dir.to_path_buf(), scale, count: 0 }
+    }
+}
+
+impl Renderer for PngRenderer {
+    fn render(&mut self, frame: &Frame) -> Result<(), String> {
+        if self.count == 0 {
+            fs::create_dir_all(&self.dir).map_err(|err| format!("Failed to create {}: {}", self.dir.display(), err))?;
+        }
+
+        let path = self.dir.join(format!("frame_{:05}.png", self.count));
+        rasterize(frame, self.scale)
+            .save(&path)
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+        self.count += 1;
+        Ok(())
+    }
+}
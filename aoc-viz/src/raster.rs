@@ -0,0 +1,76 @@
+use crate::{compose, font};
+use aoc_common::frame::{Color, Frame, Overlay};
+use image::{Rgb, RgbImage};
+
+fn rgb(color: Color) -> Rgb<u8> {
+    Rgb([color.r, color.g, color.b])
+}
+
+/// Draws `text` with its top left corner at pixel `(left, top)`, clipped to the image.
+fn draw_text(image: &mut RgbImage, left: u32, top: u32, text: &str, pixel: u32, color: Color) {
+    for (index, c) in text.chars().enumerate() {
+        let glyph_left = left + index as u32 * (font::WIDTH + 1) * pixel;
+        for y in 0..font::HEIGHT * pixel {
+            for x in 0..font::WIDTH * pixel {
+                let (px, py) = (glyph_left + x, top + y);
+                if px < image.width() && py < image.height() && font::is_set(c, x / pixel, y / pixel) {
+                    image.put_pixel(px, py, rgb(color));
+                }
+            }
+        }
+    }
+}
+
+/// The frame as an image with `scale` pixels per cell side.
+pub fn rasterize(frame: &Frame, scale: u32) -> RgbImage {
+    let scale = scale.max(1);
+    let drawn = compose(frame);
+    let mut image = RgbImage::new(drawn.width() as u32 * scale, drawn.height() as u32 * scale);
+
+    for ((row, col), cell) in drawn.iter() {
+        let fill = rgb(cell.fill());
+        for y in 0..scale {
+            for x in 0..scale {
+                image.put_pixel(col as u32 * scale + x, row as u32 * scale + y, fill);
+            }
+        }
+    }
+
+    // Font pixels are half a cell, so labels stay legible next to the grid.
+    let pixel = (scale / 2).max(1);
+    for overlay in &frame.overlays {
+        if let Overlay::Label { at, text, color } = overlay {
+            if at.x >= 0 && at.y >= 0 {
+                draw_text(&mut image, at.x as u32 * scale, at.y as u32 * scale, text, pixel, *color);
+            }
+        }
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Cell;
+    use aoc_common::geometry::Point;
+    use aoc_common::grid::Grid;
+
+    #[test]
+    fn fills_cells_and_draws_labels() {
+        let grid = Grid::parse("#.\n..").unwrap();
+        let frame = Frame::from_grid(&grid, |&c| Cell::new(c, if c == '#' { Color::GRAY } else { Color::BLACK }))
+            .label(Point::new(0, 1), "1", Color::WHITE);
+
+        let image = rasterize(&frame, 4);
+        assert_eq!(image.dimensions(), (8, 8));
+        assert_eq!(image.get_pixel(1, 1), &rgb(Color::GRAY));
+        assert_eq!(image.get_pixel(6, 1), &rgb(Color::BLACK));
+
+        // The `1` glyph's top row is 010, so at two pixels per font pixel
+        // only columns 2 and 3 of the label's first row are lit.
+        assert_eq!(image.get_pixel(1, 4), &rgb(Color::BLACK));
+        assert_eq!(image.get_pixel(2, 4), &rgb(Color::WHITE));
+        assert_eq!(image.get_pixel(4, 4), &rgb(Color::BLACK));
+    }
+}
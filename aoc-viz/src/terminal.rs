@@ -0,0 +1,104 @@
+use crate::{compose, Drawn, Renderer};
+use aoc_common::frame::{Color, Frame, Overlay};
+use std::fmt::Write as _;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+/// Draws frames with 24-bit ANSI colours.
+///
+/// With a delay each frame replaces the previous one on screen, pausing in
+/// between; without one the frames are printed one after another.
+pub struct TerminalRenderer<W: Write> {
+    out: W,
+    delay: Option<Duration>,
+}
+
+impl<W: Write> TerminalRenderer<W> {
+    pub fn new(out: W, delay: Option<Duration>) -> Self {
+        TerminalRenderer { out, delay }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/// The frame as lines of text with escape codes, labels written over the cells.
+pub fn to_ansi(frame: &Frame) -> String {
+    let mut drawn = compose(frame);
+    for overlay in &frame.overlays {
+        if let Overlay::Label { at, text, color } = overlay {
+            let Some((row, col)) = at.to_position() else { continue };
+            for (offset, glyph) in text.chars().enumerate() {
+                if let Some(cell) = drawn.get_mut((row, col + offset)) {
+                    *cell = Drawn { glyph, color: *color, background: None };
+                }
+            }
+        }
+    }
+
+    let mut text = String::new();
+    for row in drawn.rows() {
+        let mut current: Option<(Color, Option<Color>)> = None;
+        for cell in row {
+            if current != Some((cell.color, cell.background)) {
+                let Color { r, g, b } = cell.color;
+                let _ = write!(text, "{}\x1b[38;2;{};{};{}m", RESET, r, g, b);
+                if let Some(Color { r, g, b }) = cell.background {
+                    let _ = write!(text, "\x1b[48;2;{};{};{}m", r, g, b);
+                }
+                current = Some((cell.color, cell.background));
+            }
+            text.push(cell.glyph);
+        }
+        text.push_str(RESET);
+        text.push('\n');
+    }
+
+    text
+}
+
+impl<W: Write> Renderer for TerminalRenderer<W> {
+    fn render(&mut self, frame: &Frame) -> Result<(), String> {
+        let text = to_ansi(frame);
+        let written = match self.delay {
+            Some(_) => write!(self.out, "{}{}", CLEAR, text),
+            None => writeln!(self.out, "{}", text),
+        };
+        written.and_then(|_| self.out.flush()).map_err(|err| format!("Failed to write frame: {}", err))?;
+
+        if let Some(delay) = self.delay {
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::Cell;
+    use aoc_common::geometry::Point;
+    use aoc_common::grid::Grid;
+
+    #[test]
+    fn colours_runs_of_cells_and_overwrites_labels() {
+        let grid = Grid::parse("#..\n...").unwrap();
+        let frame = Frame::from_grid(&grid, |&c| Cell::new(c, if c == '#' { Color::GRAY } else { Color::BLACK }))
+            .label(Point::new(1, 1), "ok", Color::WHITE);
+
+        let mut renderer = TerminalRenderer::new(Vec::new(), None);
+        renderer.render(&frame).unwrap();
+        let output = String::from_utf8(renderer.into_inner()).unwrap();
+
+        assert_eq!(
+            output,
+            "\x1b[0m\x1b[38;2;150;150;150m#\x1b[0m\x1b[38;2;0;0;0m..\x1b[0m\n\
+             \x1b[0m\x1b[38;2;0;0;0m.\x1b[0m\x1b[38;2;255;255;255mok\x1b[0m\n\n"
+        );
+    }
+}
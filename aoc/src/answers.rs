@@ -0,0 +1,157 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Known answers, stored as `[<year>.day-<day>]` tables.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(AnswerStore::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        Self::parse(&contents).map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        Ok(AnswerStore { years: toml::from_str(contents)? })
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u8) -> Option<&str> {
+        let answers = self.years.get(&year.to_string())?.get(&format!("day-{}", day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, part: u8, answer: String) {
+        let answers = self.years.entry(year.to_string()).or_default().entry(format!("day-{}", day)).or_default();
+        match part {
+            1 => answers.part1 = Some(answer),
+            2 => answers.part2 = Some(answer),
+            _ => panic!("day {} has no part {}, only 1 and 2", day, part),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_toml()).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    }
+
+    /// Renders the store with days in numeric order, matching the hand-written file.
+    pub fn to_toml(&self) -> String {
+        let number = |name: &str| name.trim_start_matches("day-").parse::<u32>().unwrap_or(u32::MAX);
+        let mut days: Vec<(&String, &String, &DayAnswers)> = self
+            .years
+            .iter()
+            .flat_map(|(year, days)| days.iter().map(move |(name, answers)| (year, name, answers)))
+            .collect();
+        days.sort_by_key(|&(year, name, _)| (number(year), number(name), name));
+
+        let mut contents = String::new();
+        for (year, name, answers) in days {
+            if !contents.is_empty() {
+                contents.push('\n');
+            }
+            contents.push_str(&format!("[{}.{}]\n", year, name));
+            for (key, answer) in [("part1", &answers.part1), ("part2", &answers.part2)] {
+                if let Some(answer) = answer {
+                    contents.push_str(&format!("{} = {}\n", key, toml::Value::String(answer.clone())));
+                }
+            }
+        }
+
+        contents
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Status {
+    pub fn check(expected: Option<&str>, actual: &str) -> Status {
+        match expected {
+            Some(expected) if expected == actual => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[2024.day-1]
+part1 = "11"
+part2 = "31"
+
+[2023.day-1]
+part1 = "142"
+
+[2024.day-17]
+part1 = "4,6,3,5,6,3,5,2,1,0"
+"#;
+
+    #[test]
+    fn looks_up_answers_by_day_and_part() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+
+        assert_eq!(store.get(2024, 1, 1), Some("11"));
+        assert_eq!(store.get(2024, 1, 2), Some("31"));
+        assert_eq!(store.get(2023, 1, 1), Some("142"));
+        assert_eq!(store.get(2023, 1, 2), None);
+        assert_eq!(store.get(2024, 17, 1), Some("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(store.get(2024, 17, 2), None);
+        assert_eq!(store.get(2024, 2, 1), None);
+        assert_eq!(store.get(2024, 1, 3), None);
+    }
+
+    #[test]
+    fn compares_expected_with_actual() {
+        assert_eq!(Status::check(Some("11"), "11"), Status::Pass);
+        assert_eq!(Status::check(Some("11"), "12"), Status::Fail);
+        assert_eq!(Status::check(None, "12"), Status::Missing);
+    }
+
+    #[test]
+    fn records_answers_in_day_order() {
+        let mut store = AnswerStore::parse(ANSWERS).unwrap();
+        store.set(2024, 17, 2, "117440".to_string());
+        store.set(2024, 2, 1, "2".to_string());
+
+        let contents = store.to_toml();
+        assert_eq!(
+            contents,
+            "[2023.day-1]\npart1 = \"142\"\n\n[2024.day-1]\npart1 = \"11\"\npart2 = \"31\"\n\n\
+             [2024.day-2]\npart1 = \"2\"\n\n[2024.day-17]\npart1 = \"4,6,3,5,6,3,5,2,1,0\"\npart2 = \"117440\"\n"
+        );
+        assert_eq!(AnswerStore::parse(&contents).unwrap().get(2024, 17, 2), Some("117440"));
+    }
+}
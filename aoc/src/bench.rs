@@ -0,0 +1,289 @@
+use aoc_common::timing::{measure, Stats};
+use aoc_common::{Params, ParseResult, Solution};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub budget: Duration,
+}
+
+/// A sampling budget from `--max-seconds`, which must be a positive, finite number.
+pub fn parse_budget(text: &str) -> Result<Duration, String> {
+    match text.parse::<f64>().ok().filter(|&seconds| seconds > 0.0).map(Duration::try_from_secs_f64) {
+        Some(Ok(budget)) => Ok(budget),
+        _ => Err(format!("expected a positive number of seconds, found `{}`", text)),
+    }
+}
+
+/// A regression threshold from `--threshold`, in percent: finite and not negative.
+pub fn parse_threshold(text: &str) -> Result<f64, String> {
+    match text.parse::<f64>() {
+        Ok(percent) if percent.is_finite() && percent >= 0.0 => Ok(percent),
+        _ => Err(format!("expected a percentage of 0 or more, found `{}`", text)),
+    }
+}
+
+pub struct DayTimings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+pub fn bench_day<S: Solution>(input: &str, params: &Params, options: &BenchOptions) -> ParseResult<DayTimings> {
+    let parsed = S::parse_with(input, params)?;
+
+    Ok(DayTimings {
+        parse: measure(options.iterations, options.budget, || S::parse_with(input, params)),
+        part1: measure(options.iterations, options.budget, || S::part1(&parsed)),
+        part2: measure(options.iterations, options.budget, || S::part2(&parsed)),
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StageReport {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl From<Stats> for StageReport {
+    fn from(stats: Stats) -> Self {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+
+        StageReport {
+            samples: stats.samples,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            p95_ns: nanos(stats.p95),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub parse: StageReport,
+    pub part1: StageReport,
+    pub part2: StageReport,
+}
+
+impl DayReport {
+    pub fn new(year: u32, day: u32, timings: DayTimings) -> Self {
+        DayReport {
+            year,
+            day,
+            parse: timings.parse.into(),
+            part1: timings.part1.into(),
+            part2: timings.part2.into(),
+        }
+    }
+
+    fn key(&self) -> (u32, u32) {
+        (self.year, self.day)
+    }
+
+    pub fn stages(&self) -> [(&'static str, &StageReport); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Ok,
+    Improved,
+    Regressed,
+    New,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Ok => "OK",
+            Verdict::Improved => "IMPROVED",
+            Verdict::Regressed => "REGRESSED",
+            Verdict::New => "NEW",
+        }
+    }
+}
+
+pub struct Comparison {
+    pub year: u32,
+    pub day: u32,
+    pub stage: &'static str,
+    pub baseline_ns: Option<u64>,
+    pub current_ns: u64,
+    pub verdict: Verdict,
+}
+
+impl Comparison {
+    pub fn change_percent(&self) -> Option<f64> {
+        self.baseline_ns
+            .filter(|&baseline| baseline > 0)
+            .map(|baseline| (self.current_ns as f64 - baseline as f64) / baseline as f64 * 100.0)
+    }
+}
+
+pub struct Thresholds {
+    /// Slowdown in percent of the baseline median that counts as a regression.
+    pub percent: f64,
+    /// Absolute slowdown below which changes are treated as noise.
+    pub noise_floor: Duration,
+}
+
+pub fn compare(baseline: &[DayReport], current: &[DayReport], thresholds: &Thresholds) -> Vec<Comparison> {
+    let noise_floor = thresholds.noise_floor.as_nanos() as u64;
+    let mut comparisons = Vec::new();
+
+    for report in current {
+        let previous = baseline.iter().find(|previous| previous.key() == report.key());
+
+        for (index, (stage, stats)) in report.stages().into_iter().enumerate() {
+            let baseline_ns = previous.map(|previous| previous.stages()[index].1.median_ns);
+            let current_ns = stats.median_ns;
+
+            let verdict = match baseline_ns {
+                None => Verdict::New,
+                Some(baseline_ns) => {
+                    let limit = baseline_ns as f64 * (1.0 + thresholds.percent / 100.0);
+                    let change = current_ns.abs_diff(baseline_ns);
+                    if current_ns as f64 > limit && change > noise_floor {
+                        Verdict::Regressed
+                    } else if (current_ns as f64) < baseline_ns as f64 / (1.0 + thresholds.percent / 100.0)
+                        && change > noise_floor
+                    {
+                        Verdict::Improved
+                    } else {
+                        Verdict::Ok
+                    }
+                }
+            };
+
+            let (year, day) = (report.year, report.day);
+            comparisons.push(Comparison { year, day, stage, baseline_ns, current_ns, verdict });
+        }
+    }
+
+    comparisons
+}
+
+/// Replaces the baseline entries of every benchmarked day, keeping the rest.
+pub fn merge_baseline(mut baseline: Vec<DayReport>, current: &[DayReport]) -> Vec<DayReport> {
+    baseline.retain(|previous| current.iter().all(|report| report.key() != previous.key()));
+    baseline.extend(current.iter().cloned());
+    baseline.sort_by_key(DayReport::key);
+    baseline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answer;
+
+    #[test]
+    fn budgets_must_be_positive_and_finite() {
+        assert_eq!(parse_budget("0.5"), Ok(Duration::from_millis(500)));
+        for text in ["0", "-1", "NaN", "inf", "1e30", "soon"] {
+            assert!(parse_budget(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn thresholds_must_be_finite_and_not_negative() {
+        assert_eq!(parse_threshold("0"), Ok(0.0));
+        assert_eq!(parse_threshold("12.5"), Ok(12.5));
+        for text in ["-1", "-100", "NaN", "inf", "ten"] {
+            assert!(parse_threshold(text).is_err(), "{}", text);
+        }
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 0;
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.iter().map(String::len).sum::<usize>().into()
+        }
+    }
+
+    fn report(year: u32, day: u32, medians: [u64; 3]) -> DayReport {
+        let stage = |median_ns| StageReport { samples: 1, min_ns: median_ns, median_ns, p95_ns: median_ns };
+        DayReport { year, day, parse: stage(medians[0]), part1: stage(medians[1]), part2: stage(medians[2]) }
+    }
+
+    #[test]
+    fn flags_stages_slower_than_threshold() {
+        let thresholds = Thresholds { percent: 10.0, noise_floor: Duration::from_nanos(100) };
+        let baseline = [report(2024, 6, [1_000, 1_000_000, 10_000_000_000])];
+        let current = [report(2024, 6, [1_050, 1_200_000, 5_000_000_000]), report(2024, 9, [1, 2, 3])];
+
+        let verdicts: Vec<_> = compare(&baseline, &current, &thresholds)
+            .iter()
+            .map(|comparison| (comparison.day, comparison.stage, comparison.verdict))
+            .collect();
+
+        assert_eq!(
+            verdicts,
+            [
+                (6, "parse", Verdict::Ok),
+                (6, "part1", Verdict::Regressed),
+                (6, "part2", Verdict::Improved),
+                (9, "parse", Verdict::New),
+                (9, "part1", Verdict::New),
+                (9, "part2", Verdict::New),
+            ]
+        );
+    }
+
+    #[test]
+    fn comparing_with_a_negative_threshold_does_not_underflow() {
+        let thresholds = Thresholds { percent: -50.0, noise_floor: Duration::ZERO };
+        let comparisons = compare(&[report(2024, 1, [100, 100, 100])], &[report(2024, 1, [80, 100, 150])], &thresholds);
+        assert!(comparisons.iter().all(|comparison| comparison.verdict != Verdict::New));
+    }
+
+    #[test]
+    fn ignores_slowdowns_below_noise_floor() {
+        let thresholds = Thresholds { percent: 10.0, noise_floor: Duration::from_micros(10) };
+        let baseline = [report(2024, 1, [100, 100, 100])];
+        let comparisons = compare(&baseline, &[report(2024, 1, [900, 100, 100])], &thresholds);
+
+        assert_eq!(comparisons[0].verdict, Verdict::Ok);
+        assert_eq!(comparisons[0].change_percent(), Some(800.0));
+    }
+
+    #[test]
+    fn merging_replaces_only_benchmarked_days() {
+        let baseline = vec![report(2024, 1, [1, 1, 1]), report(2024, 2, [2, 2, 2]), report(2023, 2, [5, 5, 5])];
+        let merged = merge_baseline(baseline, &[report(2024, 2, [3, 3, 3]), report(2024, 3, [4, 4, 4])]);
+
+        let medians: Vec<_> =
+            merged.iter().map(|report| (report.year, report.day, report.parse.median_ns)).collect();
+        assert_eq!(medians, [(2023, 2, 5), (2024, 1, 1), (2024, 2, 3), (2024, 3, 4)]);
+    }
+
+    #[test]
+    fn times_every_stage_separately() {
+        let options = BenchOptions { iterations: 5, budget: Duration::from_secs(10) };
+        let report = DayReport::new(2024, 0, bench_day::<Lines>("a\nbb\nccc", &Params::new(), &options).unwrap());
+
+        for (_, stage) in report.stages() {
+            assert_eq!(stage.samples, 5);
+            assert!(stage.min_ns <= stage.median_ns && stage.median_ns <= stage.p95_ns);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::bench::{bench_day, BenchOptions, DayTimings};
+use crate::diff::{compare_day, Comparison};
+use crate::presets;
+use crate::report::{run_day, run_source, DayRun};
+use crate::viz::visualize_day;
+use aoc_common::frame::Frame;
+use aoc_common::input::InputSource;
+use aoc_common::rng::Rng;
+use aoc_common::{Params, ParseResult, Solution};
+use std::path::PathBuf;
+
+/// Parses and hands the day's visualization frames to the callback.
+pub type Visualize = fn(&str, &Params, &mut dyn FnMut(Frame)) -> ParseResult<()>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    /// Names of the parameters the day reads.
+    pub param_names: &'static [&'static str],
+    /// Parses once and solves the given parts, timing each stage.
+    pub run: fn(&str, &Params, &[u8]) -> ParseResult<DayRun>,
+    /// Like `run`, reading the input from a file, stdin or a memory map; errors name the source.
+    pub run_source: fn(&InputSource, &Params, &[u8]) -> Result<DayRun, String>,
+    pub bench: fn(&str, &Params, &BenchOptions) -> ParseResult<DayTimings>,
+    pub visualize: Visualize,
+    /// A random input of the given size from the given seed, if the day has a generator.
+    pub generate: fn(u64, usize) -> Option<String>,
+    /// Solves the given parts with both the optimized and the reference solver,
+    /// skipping parts without a reference.
+    pub compare: fn(&str, &Params, &[u8]) -> ParseResult<Vec<Comparison>>,
+}
+
+impl Day {
+    fn dir(&self) -> PathBuf {
+        crate::workspace_root()
+            .join(self.year.to_string())
+            .join(format!("day-{}", self.day))
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    /// Parameters from the day's `config.toml`, see [`presets::load_params`]; names
+    /// the day never reads are rejected so a misspelt `--set` is not silently ignored.
+    pub fn params(&self, preset: Option<&str>, overrides: &[String]) -> Result<Params, String> {
+        let params = presets::load_params(&self.dir().join("config.toml"), preset, overrides)?;
+        let unknown = params.iter().map(|(name, _)| name).find(|name| !self.param_names.contains(name));
+        match unknown.map(str::to_string) {
+            Some(name) => Err(format!(
+                "Day {} of {} has no parameter `{}` (it reads: {})",
+                self.day,
+                self.year,
+                name,
+                if self.param_names.is_empty() { "none".to_string() } else { self.param_names.join(", ") }
+            )),
+            None => Ok(params),
+        }
+    }
+}
+
+fn generate_day<S: Solution>(seed: u64, size: usize) -> Option<String> {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        param_names: S::PARAMS,
+        run: run_day::<S>,
+        run_source: run_source::<S>,
+        bench: bench_day::<S>,
+        visualize: visualize_day::<S>,
+        generate: generate_day::<S>,
+        compare: compare_day::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<y2024_day_1::Day1>(),
+    day::<y2024_day_2::Day2>(),
+    day::<y2024_day_3::Day3>(),
+    day::<y2024_day_4::Day4>(),
+    day::<y2024_day_5::Day5>(),
+    day::<y2024_day_6::Day6>(),
+    day::<y2024_day_7::Day7>(),
+    day::<y2024_day_8::Day8>(),
+    day::<y2024_day_9::Day9>(),
+    day::<y2024_day_10::Day10>(),
+    day::<y2024_day_11::Day11>(),
+    day::<y2024_day_12::Day12>(),
+    day::<y2024_day_13::Day13>(),
+    day::<y2024_day_14::Day14>(),
+    day::<y2024_day_15::Day15>(),
+    day::<y2024_day_16::Day16>(),
+    day::<y2024_day_17::Day17>(),
+    day::<y2024_day_18::Day18>(),
+    // `aoc new` inserts days above this line
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The most recent year with at least one solved day.
+pub fn latest_year() -> u32 {
+    DAYS.iter().map(|d| d.year).max().expect("at least one day is registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameters_must_be_ones_the_day_reads() {
+        for solver in DAYS {
+            solver.params(None, &[]).unwrap();
+        }
+        for day in [11, 14, 18] {
+            find(2024, day).unwrap().params(Some("example"), &[]).unwrap();
+        }
+
+        let robots = find(2024, 14).unwrap();
+        assert_eq!(robots.params(None, &["width=11".to_string()]).unwrap().get("width", 0), Ok(11));
+        let error = robots.params(None, &["widht=11".to_string()]).unwrap_err();
+        assert_eq!(error, "Day 14 of 2024 has no parameter `widht` (it reads: width, height, seconds)");
+        let error = find(2024, 1).unwrap().params(None, &["width=11".to_string()]).unwrap_err();
+        assert_eq!(error, "Day 1 of 2024 has no parameter `width` (it reads: none)");
+    }
+
+    #[test]
+    fn every_day_generates_inputs_its_solver_accepts() {
+        // Days scaffolded by `aoc new` have no generator until one is written.
+        for solver in DAYS.iter().filter(|solver| (solver.generate)(1, 12).is_some()) {
+            let day = solver.day;
+            for seed in 1..=3 {
+                let input = (solver.generate)(seed, 12).expect("a day generates for every seed or none");
+                assert_eq!((solver.generate)(seed, 12).as_ref(), Some(&input), "day {} is not deterministic", day);
+
+                let run = (solver.run)(&input, &Params::new(), &[1, 2])
+                    .unwrap_or_else(|err| panic!("day {} seed {}: {}\n{}", day, seed, err, input));
+                assert_eq!(run.parts.len(), 2);
+            }
+            assert_ne!((solver.generate)(1, 12), (solver.generate)(2, 12), "day {} ignores the seed", day);
+        }
+    }
+
+    #[test]
+    fn references_agree_with_optimized_solvers_on_generated_inputs() {
+        for solver in DAYS {
+            for seed in 1..=10 {
+                let input = (solver.generate)(seed, 8).unwrap_or_default();
+                let comparisons = (solver.compare)(&input, &Params::new(), &[1, 2]).unwrap();
+                let differing: Vec<_> = comparisons.iter().filter(|comparison| comparison.differs()).collect();
+                assert!(differing.is_empty(), "day {} seed {}: {:?}\n{}", solver.day, seed, differing, input);
+            }
+        }
+    }
+}
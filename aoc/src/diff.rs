@@ -0,0 +1,163 @@
+use crate::days::Day;
+use crate::runner;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+
+/// The optimized and reference answers to one part of an input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub part: u8,
+    pub optimized: Answer,
+    pub reference: Answer,
+}
+
+impl Comparison {
+    pub fn differs(&self) -> bool {
+        self.optimized != self.reference
+    }
+}
+
+/// Parses once and solves each of `parts` that has a reference both ways.
+pub fn compare_day<S: Solution>(input: &str, params: &Params, parts: &[u8]) -> ParseResult<Vec<Comparison>> {
+    let parsed = S::parse_with(input, params)?;
+
+    Ok(parts
+        .iter()
+        .filter_map(|&part| {
+            let reference = S::reference(&parsed, part)?;
+            let optimized = S::answer(&parsed, part);
+            Some(Comparison { part, optimized, reference })
+        })
+        .collect())
+}
+
+pub struct DiffOptions {
+    pub cases: u64,
+    pub size: usize,
+    pub seed: u64,
+    pub parallel: bool,
+}
+
+/// A generated input on which the solvers disagree, and the smallest input found
+/// from it that still makes them disagree on the same part.
+pub struct Mismatch {
+    pub seed: u64,
+    pub original: String,
+    pub minimized: String,
+    pub comparison: Comparison,
+}
+
+/// Compares the solvers on inputs generated from `cases` consecutive seeds and
+/// returns the parts compared and the mismatch with the lowest seed, if any.
+pub fn find_mismatch(solver: &Day, options: &DiffOptions) -> Result<(Vec<u8>, Option<Mismatch>), String> {
+    let params = Params::new();
+    let seeds: Vec<u64> = (options.seed..options.seed.saturating_add(options.cases)).collect();
+    let results = runner::run_each(&seeds, options.parallel, |&seed| {
+        let input = (solver.generate)(seed, options.size)
+            .ok_or_else(|| format!("Day {} of {} has no input generator", solver.day, solver.year))?;
+        let comparisons = (solver.compare)(&input, &params, &[1, 2]).map_err(|err| err.to_string())?;
+        Ok((input, comparisons))
+    });
+
+    let mut parts = Vec::new();
+    for (&seed, result) in seeds.iter().zip(results) {
+        let (input, comparisons) = result.map_err(|err| format!("Seed {}: {}", seed, err))?;
+        if parts.is_empty() {
+            parts = comparisons.iter().map(|comparison| comparison.part).collect();
+        }
+        if let Some(comparison) = comparisons.into_iter().find(Comparison::differs) {
+            return Ok((parts, Some(minimize_mismatch(solver, seed, input, comparison.part))));
+        }
+    }
+
+    if parts.is_empty() {
+        return Err(format!("Day {} of {} has no reference solver", solver.day, solver.year));
+    }
+    Ok((parts, None))
+}
+
+fn minimize_mismatch(solver: &Day, seed: u64, original: String, part: u8) -> Mismatch {
+    let params = Params::new();
+    // Candidates that fail to parse or panic are simply not kept.
+    let compare = |candidate: &str| -> Option<Comparison> {
+        let mut results = runner::run_each(&[candidate], false, |candidate| {
+            (solver.compare)(candidate, &params, &[part]).map_err(|err| err.to_string())
+        });
+        results.pop()?.ok()?.pop().filter(Comparison::differs)
+    };
+
+    let minimized = minimize(&original, |candidate| compare(candidate).is_some());
+    let comparison = compare(&minimized).expect("the minimized input still differs");
+    Mismatch { seed, original, minimized, comparison }
+}
+
+/// Shrinks `input` while `fails` still holds: first by dropping lines, then by
+/// dropping characters from each remaining line.
+pub fn minimize(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{}\n", line)).collect::<String>();
+
+    let lines: Vec<String> = input.lines().map(str::to_string).collect();
+    let mut lines = shrink(lines, |lines| fails(&join(lines)));
+    for index in 0..lines.len() {
+        let chars: Vec<char> = lines[index].chars().collect();
+        let chars = shrink(chars, |chars| {
+            let mut candidate = lines.clone();
+            candidate[index] = chars.iter().collect();
+            fails(&join(&candidate))
+        });
+        lines[index] = chars.into_iter().collect();
+    }
+
+    join(&lines)
+}
+
+/// Removes runs of items while `fails` still holds, starting with runs of half
+/// the items and halving the run length whenever no run of that length can go.
+fn shrink<T: Clone>(mut items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut run = (items.len() / 2).max(1);
+    while !items.is_empty() {
+        let mut removed = false;
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + run).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if fails(&candidate) {
+                items = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if !removed {
+            if run == 1 {
+                break;
+            }
+            run /= 2;
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimize_keeps_only_what_the_failure_needs() {
+        let input = "1 2 3\n4 5 6\n7 42 8\n9 10\n13 42\n";
+        let mut checks = 0;
+        let minimized = minimize(input, |candidate| {
+            checks += 1;
+            candidate.lines().filter(|line| line.contains("42")).count() == 2
+        });
+
+        assert_eq!(minimized, "42\n42\n");
+        assert!(checks < 100, "took {} checks", checks);
+    }
+
+    #[test]
+    fn minimize_returns_the_input_when_nothing_can_go() {
+        assert_eq!(minimize("ab\n", |candidate| candidate == "ab\n"), "ab\n");
+    }
+}
@@ -0,0 +1,180 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const USER_AGENT: &str = "github.com/ernestasgr/advent_of_code input fetcher";
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    year: Option<u32>,
+}
+
+/// Where and how to download puzzle inputs.
+///
+/// Read from `aoc.toml`; the `AOC_SESSION` and `AOC_BASE_URL` environment
+/// variables take precedence over the file.
+#[derive(Debug)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Year used when the command line does not name one.
+    pub year: Option<u32>,
+    pub cache_dir: PathBuf,
+}
+
+impl Config {
+    pub fn load(path: &Path, cache_dir: PathBuf, env: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let file = if path.exists() {
+            let contents =
+                fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+            toml::from_str(&contents).map_err(|err| format!("Failed to parse {}: {}", path.display(), err))?
+        } else {
+            ConfigFile::default()
+        };
+
+        Ok(Config {
+            session: env("AOC_SESSION").or(file.session).map(|session| session.trim().to_string()),
+            base_url: env("AOC_BASE_URL")
+                .or(file.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            year: file.year,
+            cache_dir,
+        })
+    }
+
+    pub fn cached_input(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day-{}", day))
+            .join("input.txt")
+    }
+
+    pub fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day)
+    }
+
+    pub fn answer_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url.trim_end_matches('/'), year, day)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| "No session token: set AOC_SESSION or `session` in aoc.toml".to_string())
+    }
+}
+
+/// Returns the cached input for `day` of `year`, downloading it first if it is not cached yet.
+pub fn fetch_input(config: &Config, year: u32, day: u32) -> Result<PathBuf, String> {
+    let path = config.cached_input(year, day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let session = config.session()?;
+    let url = config.input_url(year, day);
+
+    let body = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|err| format!("Failed to download {}: {}", url, err))?
+        .into_string()
+        .map_err(|err| format!("Failed to read response from {}: {}", url, err))?;
+
+    let parent = path.parent().expect("cached input lives in a day directory");
+    fs::create_dir_all(parent).map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+
+    // Write to a temporary file first so an interrupted download never looks cached.
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, body).map_err(|err| format!("Failed to write {}: {}", partial.display(), err))?;
+    fs::rename(&partial, &path).map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use std::env;
+    use std::sync::{Arc, Mutex};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn config(base_url: String, cache_dir: PathBuf, session: Option<&str>) -> Config {
+        Config { session: session.map(str::to_string), base_url, year: None, cache_dir }
+    }
+
+    /// Serves `body` with `status` to authorized requests for day 3.
+    fn serve(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<mock::Request>>>) {
+        mock::serve(move |request| {
+            let authorized =
+                request.url == "/2024/day/3/input" && request.cookie.as_deref() == Some("session=secret");
+            (if authorized { status } else { 400 }, body.to_string())
+        })
+    }
+    #[test]
+    fn downloads_once_and_then_uses_the_cache() {
+        let (url, requests) = serve(200, "xmul(2,4)\n");
+        let cache = temp_dir("cache");
+        let config = config(format!("{}/", url), cache.clone(), Some("secret"));
+
+        let path = fetch_input(&config, 2024, 3).unwrap();
+        assert_eq!(path, cache.join("2024").join("day-3").join("input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "xmul(2,4)\n");
+
+        fetch_input(&config, 2024, 3).unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn failed_download_leaves_nothing_cached() {
+        let (url, _) = serve(404, "not found");
+        let cache = temp_dir("missing");
+        let config = config(url, cache.clone(), Some("secret"));
+
+        let err = fetch_input(&config, 2024, 3).unwrap_err();
+        assert!(err.contains("404"), "{}", err);
+        assert!(!config.cached_input(2024, 3).exists());
+    }
+
+    #[test]
+    fn requires_a_session_token() {
+        let config = config("http://127.0.0.1:9".to_string(), temp_dir("session"), None);
+        assert!(fetch_input(&config, 2024, 3).unwrap_err().contains("AOC_SESSION"));
+    }
+
+    #[test]
+    fn environment_overrides_config_file() {
+        let dir = temp_dir("config");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("aoc.toml");
+        fs::write(&path, "session = \"from-file\"\nbase_url = \"http://file\"\nyear = 2023\n").unwrap();
+
+        let from_file = Config::load(&path, dir.clone(), |_| None).unwrap();
+        assert_eq!(from_file.session.as_deref(), Some("from-file"));
+        assert_eq!(from_file.year, Some(2023));
+        assert_eq!(from_file.input_url(2023, 5), "http://file/2023/day/5/input");
+
+        let from_env = Config::load(&path, dir.clone(), |name| Some(format!("{}-value", name))).unwrap();
+        assert_eq!(from_env.session.as_deref(), Some("AOC_SESSION-value"));
+        assert_eq!(from_env.base_url, "AOC_BASE_URL-value");
+
+        let defaults = Config::load(&dir.join("missing.toml"), dir.clone(), |_| None).unwrap();
+        assert_eq!((defaults.base_url.as_str(), defaults.year), (DEFAULT_BASE_URL, None));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
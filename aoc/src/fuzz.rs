@@ -0,0 +1,230 @@
+use crate::days::Day;
+use crate::diff::minimize;
+use aoc_common::rng::Rng;
+use aoc_common::Params;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Bytes that mean something in at least one day's format, so mutations get
+/// past the first check of a parser more often than random bytes would.
+const SYNTAX: &[u8] = b"0123456789-+,:=.#@OSE^v<>[]| \n";
+
+/// Numbers at and just past the edges of the integer types the days parse into:
+/// the largest values still parse and can overflow arithmetic, the others cannot.
+const EXTREMES: &[&str] = &[
+    "0",
+    "-1",
+    "255",
+    "65536",
+    "2147483647",
+    "-2147483648",
+    "2147483648",
+    "4294967295",
+    "4294967296",
+    "9223372036854775807",
+    "-9223372036854775808",
+    "18446744073709551615",
+    "18446744073709551616",
+];
+
+/// Starts, ends and guards on the maps of the days that walk one.
+const MARKERS: &[u8] = b"^v<>SE@";
+
+thread_local! {
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub struct FuzzOptions {
+    pub cases: u64,
+    pub seed: u64,
+    pub timeout: Duration,
+}
+
+pub enum Outcome {
+    /// Parsed and solved, or rejected with a parse error.
+    Handled,
+    /// Panicked with the hook's report: `panicked at <location>:` and the message.
+    Panicked(String),
+    TimedOut,
+}
+
+pub struct Finding {
+    pub seed: u64,
+    pub outcome: Outcome,
+    pub input: String,
+    /// The smallest input found that panics at the same place; timeouts are not minimized.
+    pub minimized: Option<String>,
+}
+
+/// The input of case `seed`: usually one of the day's generated inputs mangled
+/// by a few mutations, sometimes a tiny map or plain random bytes.
+pub fn fuzz_input(solver: &Day, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    if rng.chance(1, 4) {
+        return tiny_map(&mut rng);
+    }
+    let mut bytes = if rng.chance(1, 8) {
+        (0..rng.below(64)).map(|_| rng.below(256) as u8).collect()
+    } else {
+        let size = rng.index(8) + 1;
+        (solver.generate)(rng.next_u64(), size).unwrap_or_default().into_bytes()
+    };
+    for _ in 0..rng.between(1, 4) {
+        mutate(&mut rng, &mut bytes);
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// A map of at most 5×5 cells with one marker on it, which is sometimes walled in on
+/// all four sides, so that walks on small closed-off maps get tried.
+fn tiny_map(rng: &mut Rng) -> String {
+    let (width, height) = (rng.index(5) + 1, rng.index(5) + 1);
+    let mut rows: Vec<Vec<u8>> =
+        (0..height).map(|_| (0..width).map(|_| if rng.chance(1, 3) { b'#' } else { b'.' }).collect()).collect();
+
+    let (x, y) = if width >= 3 && height >= 3 && rng.chance(1, 2) {
+        let (x, y) = (rng.index(width - 2) + 1, rng.index(height - 2) + 1);
+        for (x, y) in [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)] {
+            rows[y][x] = b'#';
+        }
+        (x, y)
+    } else {
+        (rng.index(width), rng.index(height))
+    };
+    rows[y][x] = *rng.choose(MARKERS);
+
+    rows.into_iter().map(|row| String::from_utf8_lossy(&row).into_owned() + "\n").collect()
+}
+
+fn mutate(rng: &mut Rng, bytes: &mut Vec<u8>) {
+    let at = rng.index(bytes.len() + 1);
+    let end = |rng: &mut Rng, bytes: &[u8], longest| (at + rng.index(longest) + 1).min(bytes.len());
+
+    match rng.below(6) {
+        0 if at < bytes.len() => bytes[at] = *rng.choose(SYNTAX),
+        1 => bytes.insert(at, *rng.choose(SYNTAX)),
+        2 => {
+            let end = end(rng, bytes, 16);
+            bytes.drain(at..end);
+        }
+        3 => {
+            let end = end(rng, bytes, 32);
+            let copy = bytes[at..end].to_vec();
+            bytes.splice(at..at, copy);
+        }
+        4 => {
+            let start = bytes[at..].iter().position(u8::is_ascii_digit).map_or(bytes.len(), |offset| at + offset);
+            let digits = bytes[start..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+            bytes.splice(start..start + digits, rng.choose(EXTREMES).bytes());
+        }
+        _ => bytes.truncate(at),
+    }
+}
+
+/// Parses and solves both parts on a thread of its own, giving up after `timeout`.
+/// A day still running then keeps its thread busy until the process exits.
+pub fn run_case(solver: &'static Day, input: &str, timeout: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _ = (solver.run)(&input, &Params::new(), &[1, 2]);
+        }));
+        let outcome = match solved {
+            Ok(()) => Outcome::Handled,
+            Err(_) => Outcome::Panicked(PANIC.with(|panic| panic.borrow_mut().take()).unwrap_or_default()),
+        };
+        let _ = sender.send(outcome);
+    });
+
+    receiver.recv_timeout(timeout).unwrap_or(Outcome::TimedOut)
+}
+
+/// `panicked at <location>:`, the part of a report that stays put while the input shrinks.
+fn location(report: &str) -> &str {
+    report.lines().next().unwrap_or_default()
+}
+
+/// Runs `options.cases` fuzz cases from consecutive seeds and returns the first
+/// that panics or runs out of time.
+pub fn fuzz_day(solver: &'static Day, options: &FuzzOptions) -> Option<Finding> {
+    // Record panic reports instead of printing them; most cases are expected to be rejected quietly.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| PANIC.with(|panic| *panic.borrow_mut() = Some(info.to_string()))));
+
+    let finding = (options.seed..options.seed.saturating_add(options.cases)).find_map(|seed| {
+        let input = fuzz_input(solver, seed);
+        let outcome = run_case(solver, &input, options.timeout);
+        let minimized = match &outcome {
+            Outcome::Handled => return None,
+            Outcome::Panicked(report) => Some(minimize(&input, |candidate| {
+                let outcome = run_case(solver, candidate, options.timeout);
+                matches!(outcome, Outcome::Panicked(found) if location(&found) == location(report))
+            })),
+            Outcome::TimedOut => None,
+        };
+        Some(Finding { seed, outcome, input, minimized })
+    });
+
+    panic::set_hook(hook);
+    finding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use crate::report::DayRun;
+    use aoc_common::ParseResult;
+
+    #[test]
+    fn cases_are_reproducible_and_varied() {
+        let solver = &DAYS[0];
+        assert_eq!(fuzz_input(solver, 7), fuzz_input(solver, 7));
+
+        let inputs: Vec<String> = (0..50).map(|seed| fuzz_input(solver, seed)).collect();
+        assert!(inputs.iter().any(|input| input.contains('\u{fffd}')));
+        assert!(inputs.iter().any(|input| EXTREMES[1..].iter().any(|extreme| input.contains(extreme))));
+    }
+
+    #[test]
+    fn tiny_maps_trap_the_day_6_guard() {
+        let solver = DAYS.iter().find(|solver| solver.day == 6).unwrap();
+        let trapped = (0..200).filter_map(|seed| y2024_day_6::parse(&fuzz_input(solver, seed)).ok()).any(|input| {
+            y2024_day_6::is_guard_stuck(&input.grid, input.start_position, input.direction)
+        });
+        assert!(trapped);
+    }
+
+    fn never_finishes(_: &str, _: &Params, _: &[u8]) -> ParseResult<DayRun> {
+        thread::sleep(Duration::from_secs(60));
+        unreachable!()
+    }
+
+    static STUCK: Day = Day { run: never_finishes, ..DAYS[0] };
+
+    #[test]
+    fn days_that_never_finish_time_out() {
+        let finding = fuzz_day(&STUCK, &FuzzOptions { cases: 1, seed: 1, timeout: Duration::from_millis(50) });
+        assert!(matches!(finding, Some(Finding { outcome: Outcome::TimedOut, minimized: None, .. })));
+    }
+
+    #[test]
+    fn no_day_panics_on_mangled_input() {
+        let options = FuzzOptions { cases: 100, seed: 1, timeout: Duration::from_secs(30) };
+        for solver in DAYS {
+            if let Some(finding) = fuzz_day(solver, &options) {
+                let report = match finding.outcome {
+                    Outcome::Panicked(report) => report,
+                    _ => "did not finish".to_string(),
+                };
+                let input = finding.minimized.unwrap_or(finding.input);
+                panic!("day {} seed {} {}\n{:?}", solver.day, finding.seed, report, input);
+            }
+        }
+    }
+}
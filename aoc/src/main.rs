@@ -0,0 +1,869 @@
+mod answers;
+mod bench;
+mod days;
+mod diff;
+mod fetch;
+mod fuzz;
+#[cfg(test)]
+mod mock;
+mod presets;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod viz;
+
+use answers::{AnswerStore, Status};
+use bench::{BenchOptions, DayReport, Thresholds, Verdict};
+use diff::{DiffOptions, Mismatch};
+use fuzz::{Finding, FuzzOptions};
+use report::{DayOutput, DayRun};
+use aoc_common::input::{read_input, InputSource};
+use aoc_common::{Answer, Params, ParseError};
+use aoc_viz::{GifRenderer, PngRenderer, Renderer, TerminalRenderer};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use submit::Outcome;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON array with the answers, timings and diagnostics of every day
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum VizOutput {
+    /// Coloured text, redrawn in place
+    Terminal,
+    /// One PNG image per frame
+    Png,
+    /// A single animated GIF
+    Gif,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solver, or every day with `--all`, and print the answers
+    Run {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year; with `--all`, only this year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u32>,
+        /// Run every implemented day and summarise answers, times and verification status
+        #[arg(long, conflicts_with_all = ["day", "input", "preset", "overrides"])]
+        all: bool,
+        /// With `--all`, run days in parallel
+        #[arg(long, requires = "all")]
+        parallel: bool,
+        /// Only run this part (1 or 2); both parts run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or `-` for stdin; defaults to the day's `input.txt` or the fetched input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Map the input file into memory instead of reading it, for very large generated inputs
+        #[arg(long, requires = "input")]
+        mmap: bool,
+        /// Parameter preset from the day's `config.toml`, defaults to `real`
+        #[arg(long)]
+        preset: Option<String>,
+        /// Override a puzzle parameter; may be repeated
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        overrides: Vec<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Generate a crate for a new day and register it with the runner
+    New {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Download a day's input into the local cache unless it is already there
+    Fetch {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Solve one part and submit the answer, recording it in the known-answer store if correct
+    Submit {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Known-answer store, defaults to `answers.toml` in the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Run every solver and compare its answers with the known-answer store
+    Verify {
+        /// Only verify this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only verify this day of the year
+        #[arg(long)]
+        day: Option<u32>,
+        /// Known-answer store, defaults to `answers.toml` in the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Write a random, valid puzzle input for a day, for stress and scale testing
+    Generate {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// How large an input to generate; what it counts (lines, grid side, ...) depends on the day
+        #[arg(long)]
+        size: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// File to write, defaults to standard output
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Check a day's optimized solver against its reference solver on generated inputs
+    Diff {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// Number of inputs to generate, one per seed
+        #[arg(long, default_value_t = 1000)]
+        cases: u64,
+        /// Size of each generated input, see `generate`
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+        /// Seed of the first input
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Check the inputs on all available cores
+        #[arg(long)]
+        parallel: bool,
+    },
+    /// Feed mangled inputs to the parsers and solvers of one or every day, looking for panics
+    ///
+    /// Overflowing arithmetic only panics when overflow checks are on, as in debug builds.
+    /// Release builds turn them off and wrap silently, so a `--release` run cannot find overflows.
+    Fuzz {
+        /// Only fuzz this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only fuzz this day of the year
+        #[arg(long)]
+        day: Option<u32>,
+        /// Number of inputs per day, one per seed
+        #[arg(long, default_value_t = 10000)]
+        cases: u64,
+        /// Seed of the first input
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Count a case that runs longer than this as a finding
+        #[arg(long, default_value_t = 10)]
+        timeout_secs: u64,
+    },
+    /// Draw a day's visualization in the terminal, as PNG frames or as an animated GIF
+    Viz {
+        /// Puzzle year, defaults to `year` in aoc.toml or the latest solved year
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// Puzzle input file, or `-` for stdin; defaults to the day's `input.txt` or the fetched input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Parameter preset from the day's `config.toml`, defaults to `real`
+        #[arg(long)]
+        preset: Option<String>,
+        /// Override a puzzle parameter; may be repeated
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        overrides: Vec<String>,
+        #[arg(long, value_enum, default_value_t = VizOutput::Terminal)]
+        output: VizOutput,
+        /// Directory for PNG frames or GIF file, defaults to `output/` or `output/<year>-day-<day>.gif`
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Only draw every Nth frame; the last frame is always drawn
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+        /// Pixels per grid cell in images
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+        /// Milliseconds between frames; 0 prints terminal frames one after another
+        #[arg(long, default_value_t = 100)]
+        delay_ms: u64,
+    },
+    /// Time parse, part 1 and part 2 of each day over many iterations
+    Bench {
+        /// Only benchmark this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only benchmark this day of the year
+        #[arg(long)]
+        day: Option<u32>,
+        /// Maximum number of samples per stage
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Stop sampling a stage after this many seconds
+        #[arg(long, default_value = "5", value_parser = bench::parse_budget)]
+        max_seconds: Duration,
+        /// Where to write the JSON report, defaults to `target/bench.json`
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Record this run as the new baseline for the benchmarked days
+        #[arg(long)]
+        save_baseline: bool,
+        /// Compare this run against the baseline and fail on regressions
+        #[arg(long)]
+        compare: bool,
+        /// Baseline file, defaults to `bench_baseline.json` in the workspace root
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Median slowdown in percent that counts as a regression
+        #[arg(long, default_value = "10", value_parser = bench::parse_threshold)]
+        threshold: f64,
+        /// Ignore slowdowns smaller than this many microseconds
+        #[arg(long, default_value_t = 10)]
+        noise_floor_us: u64,
+        /// Benchmark a generated input of this size instead of the real input
+        #[arg(long, value_name = "SIZE", conflicts_with_all = ["save_baseline", "compare"])]
+        generate: Option<usize>,
+        /// Seed for `--generate`
+        #[arg(long, default_value_t = 1, requires = "generate")]
+        seed: u64,
+    },
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the workspace")
+        .to_path_buf()
+}
+
+struct RunOptions {
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    mmap: bool,
+    preset: Option<String>,
+    overrides: Vec<String>,
+    format: Format,
+}
+
+fn run(year: Option<u32>, day: u32, options: RunOptions) -> Result<(), String> {
+    let RunOptions { part, input, mmap, preset, overrides, format } = options;
+    let solver = find_day(default_year(year)?, day)?;
+    let params = solver.params(preset.as_deref(), &overrides)?;
+    if format == Format::Text && (preset.is_some() || !overrides.is_empty()) {
+        let params: Vec<String> = params.iter().map(|(name, value)| format!("{}={}", name, value)).collect();
+        println!("Parameters: {}", params.join(", "));
+    }
+    let source = match input {
+        Some(path) => InputSource::from_arg(&path, mmap)?,
+        None => InputSource::Path(input_path(solver)?),
+    };
+
+    let run = (solver.run_source)(&source, &params, &parts(part))?;
+    match format {
+        Format::Text => {
+            for part in &run.parts {
+                println!("Day {} Part {}: {}", day, part.part, part.answer);
+            }
+        }
+        Format::Json => print_json(&[DayOutput::new(solver.year, day, &params, &run)])?,
+    }
+
+    Ok(())
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run_all(year: Option<u32>, part: Option<u8>, parallel: bool, format: Format) -> Result<(), String> {
+    let answers = AnswerStore::load(&workspace_root().join("answers.toml"))?;
+    let solvers = select_days(year, None)?;
+    let parts = parts(part);
+
+    let start = Instant::now();
+    let results = runner::run_each(&solvers, parallel, |solver| {
+        let source = InputSource::Path(input_path(solver)?);
+        let params = solver.params(None, &[])?;
+        let run = (solver.run_source)(&source, &params, &parts)?;
+        Ok((params, run))
+    });
+    let wall = start.elapsed();
+
+    let mut tally = Tally::default();
+    let mut errors = 0;
+    let mut total = Duration::ZERO;
+    let mut outputs = Vec::new();
+    if format == Format::Text {
+        println!("{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  Status", "Year", "Day", "Part", "Answer", "Time");
+    }
+
+    for (solver, result) in solvers.iter().zip(results) {
+        let (params, run) = match result {
+            Ok(result) => result,
+            Err(err) => {
+                errors += 1;
+                if format == Format::Text {
+                    let (year, day) = (solver.year, solver.day);
+                    println!("{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  ERROR: {}", year, day, "-", "-", "-", err);
+                }
+                outputs.push(DayOutput::failed(solver.year, solver.day, err));
+                continue;
+            }
+        };
+
+        total += run.parse_time + run.parts.iter().map(|part| part.time).sum::<Duration>();
+        let output = check_answers(&answers, solver, &params, &run, &mut tally);
+        if format == Format::Text {
+            for (part, part_output) in run.parts.iter().zip(&output.parts) {
+                println!(
+                    "{:>4}  {:>3}  {:>4}  {:<20}  {:>12}  {}",
+                    solver.year,
+                    solver.day,
+                    part.part,
+                    part.answer.to_string(),
+                    format!("{:.2?}", part.time),
+                    part_output.status.unwrap_or("-")
+                );
+            }
+        }
+        outputs.push(output);
+    }
+
+    match format {
+        Format::Text => {
+            println!(
+                "{} days: {} passed, {} failed, {} missing, {} errors",
+                solvers.len(),
+                tally.passed,
+                tally.failed,
+                tally.missing,
+                errors
+            );
+            println!("Total time {:.2?} ({:.2?} wall clock)", total, wall);
+        }
+        Format::Json => print_json(&outputs)?,
+    }
+
+    if errors > 0 || tally.failed > 0 {
+        Err(format!("{} day(s) failed, {} answer(s) did not match", errors, tally.failed))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Tally {
+    fn record(&mut self, status: Status) {
+        match status {
+            Status::Pass => self.passed += 1,
+            Status::Fail => self.failed += 1,
+            Status::Missing => self.missing += 1,
+        }
+    }
+}
+
+/// The day's JSON output with every part checked against the known answers.
+fn check_answers(
+    answers: &AnswerStore,
+    solver: &days::Day,
+    params: &Params,
+    run: &DayRun,
+    tally: &mut Tally,
+) -> DayOutput {
+    let mut output = DayOutput::new(solver.year, solver.day, params, run);
+    for (part, part_output) in run.parts.iter().zip(&mut output.parts) {
+        let expected = answers.get(solver.year, solver.day, part.part);
+        let status = Status::check(expected, &part.answer.to_string());
+        tally.record(status);
+        part_output.expected = expected.map(str::to_string);
+        part_output.status = Some(status.label());
+    }
+
+    output
+}
+
+fn print_json(days: &[DayOutput]) -> Result<(), String> {
+    println!("{}", serde_json::to_string_pretty(days).map_err(|err| err.to_string())?);
+    Ok(())
+}
+
+fn verify(year: Option<u32>, day: Option<u32>, answers: Option<PathBuf>, format: Format) -> Result<(), String> {
+    let answers = AnswerStore::load(&answers.unwrap_or_else(|| workspace_root().join("answers.toml")))?;
+    let solvers = select_days(year, day)?;
+
+    let mut tally = Tally::default();
+    let mut outputs = Vec::new();
+    if format == Format::Text {
+        println!("{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  Status", "Year", "Day", "Part", "Expected", "Actual");
+    }
+
+    for solver in solvers {
+        let source = InputSource::Path(input_path(solver)?);
+        let params = solver.params(None, &[])?;
+        let run = (solver.run_source)(&source, &params, &[1, 2])?;
+        let output = check_answers(&answers, solver, &params, &run, &mut tally);
+
+        if format == Format::Text {
+            for (part, part_output) in run.parts.iter().zip(&output.parts) {
+                println!(
+                    "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {}",
+                    solver.year,
+                    solver.day,
+                    part.part,
+                    part_output.expected.as_deref().unwrap_or("-"),
+                    part.answer.to_string(),
+                    part_output.status.unwrap_or("-")
+                );
+            }
+        }
+
+        outputs.push(output);
+    }
+
+    match format {
+        Format::Text => println!("{} passed, {} failed, {} missing", tally.passed, tally.failed, tally.missing),
+        Format::Json => print_json(&outputs)?,
+    }
+
+    if tally.failed > 0 {
+        Err(format!("{} answer(s) did not match", tally.failed))
+    } else {
+        Ok(())
+    }
+}
+
+fn fetch_config() -> Result<fetch::Config, String> {
+    let root = workspace_root();
+    fetch::Config::load(&root.join("aoc.toml"), root.join("inputs"), |name| env::var(name).ok())
+}
+
+/// The day's committed `input.txt`, or its cached download, fetching it if needed.
+fn input_path(solver: &days::Day) -> Result<PathBuf, String> {
+    let local = solver.default_input();
+    if local.exists() {
+        Ok(local)
+    } else {
+        fetch::fetch_input(&fetch_config()?, solver.year, solver.day)
+    }
+}
+
+fn new_day(year: Option<u32>, day: u32) -> Result<(), String> {
+    let year = default_year(year)?;
+    for path in scaffold::new_day(&workspace_root(), year, day)? {
+        println!("Created {}", path.display());
+    }
+    println!("Registered day {} of {}; try `cargo run -p aoc -- run --year {} --day {}`", day, year, year, day);
+
+    Ok(())
+}
+
+fn fetch(year: Option<u32>, day: u32) -> Result<(), String> {
+    let year = default_year(year)?;
+    let path = fetch::fetch_input(&fetch_config()?, year, day)?;
+    println!("{} Day {} input: {}", year, day, path.display());
+
+    Ok(())
+}
+
+fn submit(year: Option<u32>, day: u32, part: u8, answers: Option<PathBuf>) -> Result<(), String> {
+    let solver = find_day(default_year(year)?, day)?;
+    let source = InputSource::Path(input_path(solver)?);
+    let run = (solver.run_source)(&source, &solver.params(None, &[])?, &[part])?;
+    let answer = run.parts[0].answer.clone();
+    if answer == Answer::None {
+        return Err(format!("Day {} Part {} has no answer yet", day, part));
+    }
+    let answer = answer.to_string();
+
+    let answers_path = answers.unwrap_or_else(|| workspace_root().join("answers.toml"));
+    let mut store = AnswerStore::load(&answers_path)?;
+    match store.get(solver.year, day, part) {
+        Some(known) if known == answer => {
+            println!("Day {} Part {}: {} is already recorded as correct", day, part, answer);
+            return Ok(());
+        }
+        Some(known) => {
+            return Err(format!("Day {} Part {}: computed {} but {} is recorded as correct", day, part, answer, known))
+        }
+        None => {}
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|err| err.to_string())?.as_secs();
+    let reply = submit::submit_answer(&fetch_config()?, solver.year, day, part, &answer, now)?;
+    println!("Day {} Part {}: {} is {}", day, part, answer, reply.outcome);
+    if reply.cooldown > 0 {
+        println!("Next submission allowed in {}s", reply.cooldown);
+    }
+
+    match reply.outcome {
+        Outcome::Correct => {
+            store.set(solver.year, day, part, answer);
+            store.save(&answers_path)?;
+            println!("Recorded in {}", answers_path.display());
+            Ok(())
+        }
+        Outcome::AlreadySolved => Ok(()),
+        outcome => Err(format!("Answer was not accepted: {}", outcome)),
+    }
+}
+
+fn generate(year: Option<u32>, day: u32, size: usize, seed: u64, out: Option<PathBuf>) -> Result<(), String> {
+    let solver = find_day(default_year(year)?, day)?;
+    let input = generated_input(solver, size, seed)?;
+    match out {
+        Some(path) => {
+            fs::write(&path, input).map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+            println!("Wrote {}", path.display());
+        }
+        None => print!("{}", input),
+    }
+
+    Ok(())
+}
+
+fn generated_input(solver: &days::Day, size: usize, seed: u64) -> Result<String, String> {
+    (solver.generate)(seed, size).ok_or_else(|| format!("Day {} of {} has no input generator", solver.day, solver.year))
+}
+
+fn diff(year: Option<u32>, day: u32, options: DiffOptions) -> Result<(), String> {
+    let solver = find_day(default_year(year)?, day)?;
+    let (parts, mismatch) = diff::find_mismatch(solver, &options)?;
+    let parts: Vec<String> = parts.iter().map(u8::to_string).collect();
+
+    let Some(Mismatch { seed, original, minimized, comparison }) = mismatch else {
+        println!("Part {} agreed with the reference on {} inputs", parts.join(" and part "), options.cases);
+        return Ok(());
+    };
+
+    println!(
+        "Part {} differs on the input of seed {} (size {}), minimized from {} to {} lines:",
+        comparison.part,
+        seed,
+        options.size,
+        original.lines().count(),
+        minimized.lines().count()
+    );
+    print!("{}", minimized);
+    println!("Optimized: {}", comparison.optimized);
+    println!("Reference: {}", comparison.reference);
+    Err(format!("Day {} part {} disagrees with its reference solver", day, comparison.part))
+}
+
+fn fuzz(year: Option<u32>, day: Option<u32>, options: FuzzOptions) -> Result<(), String> {
+    let solvers = select_days(year, day)?;
+    let mut failures = 0;
+    for solver in solvers {
+        let Some(Finding { seed, outcome, input, minimized }) = fuzz::fuzz_day(solver, &options) else {
+            println!("{} day {}: no panics in {} cases", solver.year, solver.day, options.cases);
+            continue;
+        };
+
+        failures += 1;
+        match outcome {
+            fuzz::Outcome::Panicked(report) => println!("{} day {} seed {} {}", solver.year, solver.day, seed, report),
+            _ => println!("{} day {} seed {}: no answer after {:?}", solver.year, solver.day, seed, options.timeout),
+        }
+        match minimized {
+            Some(minimized) => println!("Minimized from {} to {} bytes: {:?}", input.len(), minimized.len(), minimized),
+            None => println!("Input: {:?}", input),
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!("{} days failed on fuzzed input", failures)),
+    }
+}
+
+/// Labels a parse error with the input's source.
+fn in_file(err: ParseError, source: impl fmt::Display) -> String {
+    err.with_file(source.to_string()).to_string()
+}
+
+fn default_year(year: Option<u32>) -> Result<u32, String> {
+    match year {
+        Some(year) => Ok(year),
+        None => Ok(fetch_config()?.year.unwrap_or_else(days::latest_year)),
+    }
+}
+
+fn find_day(year: u32, day: u32) -> Result<&'static days::Day, String> {
+    days::find(year, day).ok_or_else(|| format!("Day {} of {} is not implemented", day, year))
+}
+
+fn select_days(year: Option<u32>, day: Option<u32>) -> Result<Vec<&'static days::Day>, String> {
+    match (year, day) {
+        (_, Some(day)) => Ok(vec![find_day(default_year(year)?, day)?]),
+        (Some(year), None) => {
+            let solvers: Vec<_> = days::DAYS.iter().filter(|solver| solver.year == year).collect();
+            if solvers.is_empty() {
+                Err(format!("No days of {} are implemented", year))
+            } else {
+                Ok(solvers)
+            }
+        }
+        (None, None) => Ok(days::DAYS.iter().collect()),
+    }
+}
+
+struct VizOptions {
+    input: Option<PathBuf>,
+    preset: Option<String>,
+    overrides: Vec<String>,
+    output: VizOutput,
+    out: Option<PathBuf>,
+    every: usize,
+    scale: u32,
+    delay: Duration,
+}
+
+fn viz(year: Option<u32>, day: u32, options: VizOptions) -> Result<(), String> {
+    let VizOptions { input, preset, overrides, output, out, every, scale, delay } = options;
+    let solver = find_day(default_year(year)?, day)?;
+    let params = solver.params(preset.as_deref(), &overrides)?;
+    let source = match input {
+        Some(path) => InputSource::from_arg(&path, false)?,
+        None => InputSource::Path(input_path(solver)?),
+    };
+    let input = source.read().map_err(|err| format!("Failed to read {}: {}", source, err))?;
+
+    let out = out.unwrap_or_else(|| match output {
+        VizOutput::Gif => Path::new("output").join(format!("{}-day-{}.gif", solver.year, day)),
+        _ => PathBuf::from("output"),
+    });
+    let renderer: Box<dyn Renderer> = match output {
+        VizOutput::Terminal => Box::new(TerminalRenderer::new(io::stdout(), (!delay.is_zero()).then_some(delay))),
+        VizOutput::Png => Box::new(PngRenderer::new(&out, scale)),
+        VizOutput::Gif => Box::new(GifRenderer::new(&out, scale, delay)),
+    };
+
+    let mut sampler = viz::Sampler::new(renderer, every);
+    (solver.visualize)(&input, &params, &mut |frame| sampler.push(frame)).map_err(|err| in_file(err, &source))?;
+    let (drawn, emitted) = sampler.finish()?;
+
+    if emitted == 0 {
+        return Err(format!("Day {} of {} has no visualization", day, solver.year));
+    }
+    if output != VizOutput::Terminal {
+        println!("Drew {} of {} frames to {}", drawn, emitted, out.display());
+    }
+
+    Ok(())
+}
+
+struct BaselineOptions {
+    path: PathBuf,
+    save: bool,
+    compare: bool,
+    thresholds: Thresholds,
+}
+
+fn bench(
+    year: Option<u32>,
+    day: Option<u32>,
+    options: BenchOptions,
+    output: Option<PathBuf>,
+    baseline: BaselineOptions,
+    generate: Option<(usize, u64)>,
+) -> Result<(), String> {
+    let mut reports = Vec::new();
+    println!(
+        "{:>4}  {:>3}  {:<6}  {:>7}  {:>12}  {:>12}  {:>12}",
+        "Year", "Day", "Stage", "Samples", "Min", "Median", "P95"
+    );
+
+    for solver in select_days(year, day)? {
+        let (input, path) = match generate {
+            Some((size, seed)) => {
+                let source = format!("generated input (size {}, seed {})", size, seed);
+                (generated_input(solver, size, seed)?, PathBuf::from(source))
+            }
+            None => {
+                let path = input_path(solver)?;
+                let input = read_input(&path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+                (input, path)
+            }
+        };
+        let params = solver.params(None, &[])?;
+        let timings = (solver.bench)(&input, &params, &options).map_err(|err| in_file(err, path.display()))?;
+        let report = DayReport::new(solver.year, solver.day, timings);
+
+        for (stage, stats) in report.stages() {
+            println!(
+                "{:>4}  {:>3}  {:<6}  {:>7}  {:>12}  {:>12}  {:>12}",
+                solver.year,
+                solver.day,
+                stage,
+                stats.samples,
+                format!("{:.2?}", Duration::from_nanos(stats.min_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.median_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.p95_ns)),
+            );
+        }
+
+        reports.push(report);
+    }
+
+    let output = output.unwrap_or_else(|| workspace_root().join("target").join("bench.json"));
+    let json = serde_json::to_string_pretty(&reports).map_err(|err| err.to_string())?;
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    fs::write(&output, json).map_err(|err| format!("Failed to write {}: {}", output.display(), err))?;
+    println!("Wrote {}", output.display());
+
+    let mut regressions = 0;
+    if baseline.compare {
+        let previous = load_baseline(&baseline.path)?;
+        regressions = compare_with_baseline(&previous, &reports, &baseline.thresholds);
+    }
+
+    if baseline.save {
+        let merged = bench::merge_baseline(load_baseline(&baseline.path)?, &reports);
+        let json = serde_json::to_string_pretty(&merged).map_err(|err| err.to_string())?;
+        fs::write(&baseline.path, json)
+            .map_err(|err| format!("Failed to write {}: {}", baseline.path.display(), err))?;
+        println!("Saved baseline to {}", baseline.path.display());
+    }
+
+    if regressions > 0 {
+        Err(format!("{} stage(s) regressed beyond {}%", regressions, baseline.thresholds.percent))
+    } else {
+        Ok(())
+    }
+}
+
+fn load_baseline(path: &Path) -> Result<Vec<DayReport>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            serde_json::from_str(&contents).map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("Failed to read {}: {}", path.display(), err)),
+    }
+}
+
+fn compare_with_baseline(baseline: &[DayReport], reports: &[DayReport], thresholds: &Thresholds) -> usize {
+    println!();
+    println!(
+        "{:>4}  {:>3}  {:<6}  {:>12}  {:>12}  {:>8}  Status",
+        "Year", "Day", "Stage", "Baseline", "Current", "Change"
+    );
+
+    let comparisons = bench::compare(baseline, reports, thresholds);
+    for comparison in &comparisons {
+        println!(
+            "{:>4}  {:>3}  {:<6}  {:>12}  {:>12}  {:>8}  {}",
+            comparison.year,
+            comparison.day,
+            comparison.stage,
+            comparison
+                .baseline_ns
+                .map_or_else(|| "-".to_string(), |ns| format!("{:.2?}", Duration::from_nanos(ns))),
+            format!("{:.2?}", Duration::from_nanos(comparison.current_ns)),
+            comparison.change_percent().map_or_else(|| "-".to_string(), |change| format!("{:+.1}%", change)),
+            comparison.verdict.label()
+        );
+    }
+
+    comparisons.iter().filter(|comparison| comparison.verdict == Verdict::Regressed).count()
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { year, day, all, parallel, part, input, mmap, preset, overrides, format } => match day {
+            _ if all => run_all(year, part, parallel, format),
+            Some(day) => run(year, day, RunOptions { part, input, mmap, preset, overrides, format }),
+            None => Err("Either --day or --all is required".to_string()),
+        },
+        Command::New { year, day } => new_day(year, day),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit { year, day, part, answers } => submit(year, day, part, answers),
+        Command::Verify { year, day, answers, format } => verify(year, day, answers, format),
+        Command::Generate { year, day, size, seed, out } => generate(year, day, size, seed, out),
+        Command::Diff { year, day, cases, size, seed, parallel } => {
+            diff(year, day, DiffOptions { cases, size, seed, parallel })
+        }
+        Command::Fuzz { year, day, cases, seed, timeout_secs } => {
+            fuzz(year, day, FuzzOptions { cases, seed, timeout: Duration::from_secs(timeout_secs) })
+        }
+        Command::Viz { year, day, input, preset, overrides, output, out, every, scale, delay_ms } => {
+            let delay = Duration::from_millis(delay_ms);
+            let every = every as usize;
+            viz(year, day, VizOptions { input, preset, overrides, output, out, every, scale, delay })
+        }
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            max_seconds,
+            output,
+            save_baseline,
+            compare,
+            baseline,
+            threshold,
+            noise_floor_us,
+            generate,
+            seed,
+        } => {
+            let options = BenchOptions { iterations, budget: max_seconds };
+            let baseline = BaselineOptions {
+                path: baseline.unwrap_or_else(|| workspace_root().join("bench_baseline.json")),
+                save: save_baseline,
+                compare,
+                thresholds: Thresholds { percent: threshold, noise_floor: Duration::from_micros(noise_floor_us) },
+            };
+            bench(year, day, options, output, baseline, generate.map(|size| (size, seed)))
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
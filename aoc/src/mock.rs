@@ -0,0 +1,44 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// What the mock server saw of one request.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Starts a local server answering every request with `respond`; returns its
+/// base URL and the log of requests it received.
+pub fn serve(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for mut incoming in server.incoming_requests() {
+            let mut body = String::new();
+            incoming.as_reader().read_to_string(&mut body).unwrap();
+            let request = Request {
+                method: incoming.method().to_string(),
+                url: incoming.url().to_string(),
+                cookie: incoming
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string()),
+                body,
+            };
+
+            let (status, reply) = respond(&request);
+            log.lock().unwrap().push(request);
+            incoming.respond(Response::from_string(reply).with_status_code(status)).unwrap();
+        }
+    });
+
+    (url, requests)
+}
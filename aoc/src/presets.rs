@@ -0,0 +1,121 @@
+use aoc_common::Params;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use toml::Value;
+
+/// Preset used when none is named on the command line.
+pub const DEFAULT_PRESET: &str = "real";
+
+fn to_param(name: &str, value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Integer(number) => Ok(number.to_string()),
+        Value::Float(number) => Ok(number.to_string()),
+        Value::Boolean(flag) => Ok(flag.to_string()),
+        Value::Array(values) => {
+            let values = values.iter().map(|value| to_param(name, value)).collect::<Result<Vec<_>, _>>()?;
+            Ok(values.join(","))
+        }
+        _ => Err(format!("Parameter `{}` must be a string, number, boolean or array", name)),
+    }
+}
+
+/// Splits a command-line `name=value` override.
+pub fn parse_override(text: &str) -> Result<(String, String), String> {
+    match text.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("Expected NAME=VALUE, found `{}`", text)),
+    }
+}
+
+/// The parameters of `preset` from a day's `config.toml`, with `overrides` applied on top.
+///
+/// A missing file or a missing default preset means the solver's built-in values;
+/// naming a preset that does not exist is an error.
+pub fn load_params(path: &Path, preset: Option<&str>, overrides: &[String]) -> Result<Params, String> {
+    let presets: BTreeMap<String, BTreeMap<String, Value>> = if path.exists() {
+        let contents = fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        toml::from_str(&contents).map_err(|err| format!("Failed to parse {}: {}", path.display(), err))?
+    } else {
+        BTreeMap::new()
+    };
+
+    let mut params = Params::new();
+    match presets.get(preset.unwrap_or(DEFAULT_PRESET)) {
+        Some(values) => {
+            for (name, value) in values {
+                params.set(name.as_str(), to_param(name, value)?);
+            }
+        }
+        None if preset.is_none() => {}
+        None => {
+            let available: Vec<&str> = presets.keys().map(String::as_str).collect();
+            return Err(format!(
+                "No preset `{}` in {} (available: {})",
+                preset.unwrap_or(DEFAULT_PRESET),
+                path.display(),
+                if available.is_empty() { "none".to_string() } else { available.join(", ") }
+            ));
+        }
+    }
+
+    for text in overrides {
+        let (name, value) = parse_override(text)?;
+        params.set(name, value);
+    }
+
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const CONFIG: &str = "\
+[real]
+width = 101
+height = 103
+
+[example]
+width = 11
+height = 7
+stones = [125, 17]
+";
+
+    fn write_config(name: &str) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("aoc-presets-{}-{}.toml", name, std::process::id()));
+        fs::write(&path, CONFIG).unwrap();
+        path
+    }
+
+    #[test]
+    fn selects_presets_and_applies_overrides() {
+        let path = write_config("select");
+
+        let real = load_params(&path, None, &[]).unwrap();
+        assert_eq!(real.get("width", 0), Ok(101));
+
+        let example = load_params(&path, Some("example"), &["height = 9".to_string()]).unwrap();
+        let values: Vec<_> = example.iter().collect();
+        assert_eq!(values, [("height", "9"), ("stones", "125,17"), ("width", "11")]);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn unknown_presets_and_bad_overrides_are_errors() {
+        let path = write_config("unknown");
+
+        let err = load_params(&path, Some("small"), &[]).unwrap_err();
+        assert!(err.contains("available: example, real"), "{}", err);
+        assert!(load_params(&path, None, &["width".to_string()]).unwrap_err().contains("NAME=VALUE"));
+
+        let missing = path.with_extension("missing");
+        assert_eq!(load_params(&missing, None, &["width=5".to_string()]).unwrap(), Params::new().with("width", "5"));
+        assert!(load_params(&missing, Some("example"), &[]).is_err());
+
+        fs::remove_file(path).unwrap();
+    }
+}
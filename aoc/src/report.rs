@@ -0,0 +1,181 @@
+use aoc_common::input::InputSource;
+use aoc_common::timing::timed;
+use aoc_common::{Answer, Params, ParseResult, Solution};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// One timed run of a day: parse once, then solve the requested parts.
+pub struct DayRun {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+    pub diagnostics: Vec<(&'static str, Answer)>,
+}
+
+pub fn run_day<S: Solution>(input: &str, params: &Params, parts: &[u8]) -> ParseResult<DayRun> {
+    let (parsed, parse_time) = timed(|| S::parse_with(input, params));
+    Ok(solve_parts::<S>(parsed?, parse_time, parts))
+}
+
+/// Like [`run_day`], reading the input from `source`; the parse time includes the reading.
+pub fn run_source<S: Solution>(source: &InputSource, params: &Params, parts: &[u8]) -> Result<DayRun, String> {
+    let (parsed, parse_time) = timed(|| S::parse_source(source, params));
+    Ok(solve_parts::<S>(parsed?, parse_time, parts))
+}
+
+fn solve_parts<S: Solution>(parsed: S::Input, parse_time: Duration, parts: &[u8]) -> DayRun {
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, time) = timed(|| S::answer(&parsed, part));
+            PartRun { part, answer, time }
+        })
+        .collect();
+
+    DayRun { parse_time, parts, diagnostics: S::diagnostics(&parsed) }
+}
+
+/// Answers keep their natural JSON shape: numbers, `[x, y]` pairs, arrays or strings.
+pub fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Integer(value) => json!(value),
+        Answer::Text(text) => json!(text),
+        Answer::Coordinate(x, y) => json!([x, y]),
+        Answer::List(values) => json!(values),
+        Answer::None => Value::Null,
+    }
+}
+
+#[derive(Serialize)]
+pub struct PartOutput {
+    pub part: u8,
+    pub answer: Value,
+    pub time_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'static str>,
+}
+
+/// A day's entry in `--format json` output.
+#[derive(Serialize)]
+pub struct DayOutput {
+    pub year: u32,
+    pub day: u32,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    pub parse_ns: u64,
+    pub parts: Vec<PartOutput>,
+    pub diagnostics: Map<String, Value>,
+    /// Why the day produced no answers, when it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DayOutput {
+    pub fn new(year: u32, day: u32, params: &Params, run: &DayRun) -> Self {
+        DayOutput {
+            year,
+            day,
+            params: params.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            parse_ns: run.parse_time.as_nanos() as u64,
+            parts: run
+                .parts
+                .iter()
+                .map(|part| PartOutput {
+                    part: part.part,
+                    answer: answer_json(&part.answer),
+                    time_ns: part.time.as_nanos() as u64,
+                    expected: None,
+                    status: None,
+                })
+                .collect(),
+            diagnostics: run
+                .diagnostics
+                .iter()
+                .map(|(name, value)| (name.to_string(), answer_json(value)))
+                .collect(),
+            error: None,
+        }
+    }
+
+    pub fn failed(year: u32, day: u32, error: String) -> Self {
+        DayOutput {
+            year,
+            day,
+            params: BTreeMap::new(),
+            parse_ns: 0,
+            parts: Vec::new(),
+            diagnostics: Map::new(),
+            error: Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Words;
+
+    impl Solution for Words {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 0;
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            Ok(input.split_whitespace().map(str::to_string).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.join("-").into()
+        }
+
+        fn diagnostics(input: &Self::Input) -> Vec<(&'static str, Answer)> {
+            vec![("lengths", input.iter().map(|word| word.len() as i64).collect::<Vec<_>>().into())]
+        }
+    }
+
+    #[test]
+    fn serializes_answers_timings_and_diagnostics() {
+        let params = Params::new().with("size", "7");
+        let run = run_day::<Words>("ab cde", &params, &[1, 2]).unwrap();
+        let mut output = serde_json::to_value(DayOutput::new(2024, 0, &params, &run)).unwrap();
+
+        for part in output["parts"].as_array_mut().unwrap() {
+            assert!(part["time_ns"].is_u64());
+            part.as_object_mut().unwrap().remove("time_ns");
+        }
+        assert!(output["parse_ns"].is_u64());
+        output.as_object_mut().unwrap().remove("parse_ns");
+
+        assert_eq!(
+            output,
+            json!({
+                "year": 2024,
+                "day": 0,
+                "params": { "size": "7" },
+                "parts": [{ "part": 1, "answer": 2 }, { "part": 2, "answer": "ab-cde" }],
+                "diagnostics": { "lengths": [2, 3] },
+            })
+        );
+    }
+
+    #[test]
+    fn coordinates_and_missing_answers_have_json_shapes() {
+        assert_eq!(answer_json(&Answer::Coordinate(28, 44)), json!([28, 44]));
+        assert_eq!(answer_json(&Answer::None), Value::Null);
+    }
+}
@@ -0,0 +1,88 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Runs `job` on a single item, turning a panic into an error.
+fn isolated<T, R>(item: &T, job: &(impl Fn(&T) -> Result<R, String> + Sync)) -> Result<R, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| job(item)))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload.as_ref()))))
+}
+
+/// Runs `job` on every item, one worker per available core when `parallel` is set,
+/// and returns the results in the order of `items`. A failing or panicking item
+/// does not stop the others.
+pub fn run_each<T, R, F>(items: &[T], parallel: bool, job: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R, String> + Sync,
+{
+    // Panics become table rows, so keep the default hook from also printing them.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let results = if parallel {
+        let workers = thread::available_parallelism().map_or(1, |count| count.get()).min(items.len().max(1));
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else { break };
+                    let result = isolated(item, &job);
+                    results.lock().unwrap()[index] = Some(result);
+                });
+            }
+        });
+
+        results.into_inner().unwrap().into_iter().map(|result| result.expect("every item was run")).collect()
+    } else {
+        items.iter().map(|item| isolated(item, &job)).collect()
+    };
+
+    panic::set_hook(hook);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(&n: &u32) -> Result<u32, String> {
+        match n {
+            3 => panic!("day {} exploded", n),
+            5 => Err("bad input".to_string()),
+            _ => Ok(n * 10),
+        }
+    }
+
+    #[test]
+    fn failures_do_not_stop_other_items() {
+        let items: Vec<u32> = (1..=6).collect();
+        let expected = vec![
+            Ok(10),
+            Ok(20),
+            Err("panicked: day 3 exploded".to_string()),
+            Ok(40),
+            Err("bad input".to_string()),
+            Ok(60),
+        ];
+
+        assert_eq!(run_each(&items, false, job), expected);
+        assert_eq!(run_each(&items, true, job), expected);
+    }
+}
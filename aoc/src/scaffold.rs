@@ -0,0 +1,128 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.tmpl");
+
+const DEPENDENCY_MARKER: &str = "# `aoc new` inserts day dependencies above this line";
+const REGISTRY_MARKER: &str = "// `aoc new` inserts days above this line";
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{crate}}", &format!("y{}_day_{}", year, day))
+}
+
+/// Inserts `entry` on its own line just above the line holding `marker`.
+fn insert_before_marker(path: &Path, marker: &str, entry: &str) -> Result<(), String> {
+    let contents = fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+    if contents.lines().any(|line| line.trim() == entry.trim()) {
+        return Ok(());
+    }
+
+    let marker_start = contents
+        .find(marker)
+        .ok_or_else(|| format!("{} has no `{}` line", path.display(), marker))?;
+    let line_start = contents[..marker_start].rfind('\n').map_or(0, |newline| newline + 1);
+
+    let mut updated = contents[..line_start].to_string();
+    updated.push_str(entry);
+    updated.push('\n');
+    updated.push_str(&contents[line_start..]);
+
+    fs::write(path, updated).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+/// Creates the `<year>/day-<day>` crate under `root` and registers it with the
+/// runner; returns the files that were created.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(year.to_string()).join(format!("day-{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src).map_err(|err| format!("Failed to create {}: {}", src.display(), err))?;
+
+    let files = [(dir.join("Cargo.toml"), CARGO_TOML), (src.join("lib.rs"), LIB_RS), (src.join("main.rs"), MAIN_RS)];
+    for (path, template) in &files {
+        fs::write(path, render(template, year, day))
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+    }
+
+    insert_before_marker(
+        &root.join("aoc").join("Cargo.toml"),
+        DEPENDENCY_MARKER,
+        &format!("y{year}-day-{day} = {{ path = \"../{year}/day-{day}\" }}", year = year, day = day),
+    )?;
+    insert_before_marker(
+        &root.join("aoc").join("src").join("days.rs"),
+        REGISTRY_MARKER,
+        &format!("    day::<y{}_day_{}::Day{}>(),", year, day, day),
+    )?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn fake_workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            format!("[dependencies]\ny2024-day-1 = {{ path = \"../2024/day-1\" }}\n{}\n", DEPENDENCY_MARKER),
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            format!("pub const DAYS: &[Day] = &[\n    day::<y2024_day_1::Day1>(),\n    {}\n];\n", REGISTRY_MARKER),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn generates_crate_and_registers_it() {
+        let root = fake_workspace("new");
+        let files = new_day(&root, 2025, 3).unwrap();
+        assert_eq!(files.len(), 3);
+
+        let manifest = fs::read_to_string(root.join("2025/day-3/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"y2025-day-3\""));
+        let lib = fs::read_to_string(root.join("2025/day-3/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day3 {\n    const YEAR: u32 = 2025;\n    const DAY: u32 = 3;"));
+        let main = fs::read_to_string(root.join("2025/day-3/src/main.rs")).unwrap();
+        assert!(main.contains("run_main::<y2025_day_3::Day3>"));
+        assert!(!lib.contains("{{") && !main.contains("{{") && !manifest.contains("{{"));
+
+        let dependencies = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(dependencies.ends_with(&format!(
+            "y2025-day-3 = {{ path = \"../2025/day-3\" }}\n{}\n",
+            DEPENDENCY_MARKER
+        )));
+        let registry = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains(&format!(
+            "    day::<y2024_day_1::Day1>(),\n    day::<y2025_day_3::Day3>(),\n    {}\n",
+            REGISTRY_MARKER
+        )));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_overwrite_an_existing_day() {
+        let root = fake_workspace("existing");
+        fs::create_dir_all(root.join("2024/day-1")).unwrap();
+
+        assert!(new_day(&root, 2024, 1).unwrap_err().contains("already exists"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
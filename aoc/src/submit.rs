@@ -0,0 +1,270 @@
+use crate::fetch::{Config, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Wait imposed after a wrong answer when the reply does not say how long.
+const DEFAULT_PENALTY_SECONDS: u64 = 60;
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// The previous submission was too recent; nothing was checked.
+    Wait,
+    AlreadySolved,
+    /// The reply's text, when it matched none of the known messages.
+    Unrecognized(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait => write!(f, "submitted too recently"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub outcome: Outcome,
+    /// Seconds before the server accepts another answer.
+    pub cooldown: u64,
+}
+
+/// Reads the verdict out of the answer page's `<article>`.
+pub fn parse_reply(body: &str) -> Reply {
+    let text = article_text(body);
+    let lower = text.to_lowercase();
+
+    let outcome = if lower.contains("that's the right answer") {
+        Outcome::Correct
+    } else if lower.contains("answer too recently") {
+        Outcome::Wait
+    } else if lower.contains("not the right answer") {
+        if lower.contains("too high") {
+            Outcome::TooHigh
+        } else if lower.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if lower.contains("right level") || lower.contains("already complete") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unrecognized(text)
+    };
+
+    let cooldown = match outcome {
+        Outcome::Wait => between(&lower, "you have ", " left to wait").and_then(parse_wait).unwrap_or(0),
+        Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect => between(&lower, "wait ", " before trying again")
+            .and_then(parse_wait)
+            .unwrap_or(DEFAULT_PENALTY_SECONDS),
+        _ => 0,
+    };
+
+    Reply { outcome, cooldown }
+}
+
+/// The text inside the first `<article>`, or the whole body, without tags.
+fn article_text(body: &str) -> String {
+    let article = between(body, "<article", "</article>")
+        .map(|article| article.split_once('>').map_or(article, |(_, inner)| inner))
+        .unwrap_or(body);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The text between the first `end` and the closest `start` before it.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (before, _) = text.split_once(end)?;
+    Some(before.rsplit_once(start)?.1)
+}
+
+/// Parses waits such as `37s`, `1m 5s` or `one minute` into seconds.
+fn parse_wait(text: &str) -> Option<u64> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut seconds = 0;
+    let mut index = 0;
+
+    while index < words.len() {
+        let word = words[index];
+        let (amount, unit) = match word.find(|c: char| !c.is_ascii_digit()) {
+            Some(0) | None => {
+                let amount = match word {
+                    "a" | "an" | "one" => 1,
+                    "two" => 2,
+                    "three" => 3,
+                    "four" => 4,
+                    "five" => 5,
+                    "ten" => 10,
+                    _ => word.parse().ok()?,
+                };
+                index += 1;
+                (amount, *words.get(index)?)
+            }
+            Some(split) => (word[..split].parse().ok()?, &word[split..]),
+        };
+
+        seconds += amount
+            * match unit.trim_end_matches(['.', ',']) {
+                "s" | "second" | "seconds" => 1,
+                "m" | "minute" | "minutes" => 60,
+                "h" | "hour" | "hours" => 3600,
+                _ => return None,
+            };
+        index += 1;
+    }
+
+    Some(seconds).filter(|&seconds| seconds > 0)
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Cooldown {
+    /// Unix time in seconds before which no answer is sent.
+    until: u64,
+}
+
+fn load_cooldown(path: &Path) -> Result<Cooldown, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).map_err(|err| format!("Failed to parse {}: {}", path.display(), err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Cooldown::default()),
+        Err(err) => Err(format!("Failed to read {}: {}", path.display(), err)),
+    }
+}
+
+fn save_cooldown(path: &Path, cooldown: &Cooldown) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    let contents = toml::to_string(cooldown).map_err(|err| err.to_string())?;
+    fs::write(path, contents).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+/// Posts `answer` for a puzzle part, unless the local cooldown from a previous
+/// reply is still running at `now` (Unix seconds).
+pub fn submit_answer(config: &Config, year: u32, day: u32, part: u8, answer: &str, now: u64) -> Result<Reply, String> {
+    let cooldown_path = config.cache_dir.join("cooldown.toml");
+    let cooldown = load_cooldown(&cooldown_path)?;
+    if now < cooldown.until {
+        return Err(format!("Submissions are on cooldown for another {}s", cooldown.until - now));
+    }
+
+    let session = config.session()?;
+    let url = config.answer_url(year, day);
+    let body = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|err| format!("Failed to submit to {}: {}", url, err))?
+        .into_string()
+        .map_err(|err| format!("Failed to read response from {}: {}", url, err))?;
+
+    let reply = parse_reply(&body);
+    if reply.cooldown > 0 {
+        save_cooldown(&cooldown_path, &Cooldown { until: now + reply.cooldown })?;
+    }
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{}</p></article></main></body></html>", article)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn recognizes_every_verdict() {
+        let correct =
+            page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(parse_reply(&correct), Reply { outcome: Outcome::Correct, cooldown: 0 });
+
+        let high = page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the \
+             full input data.  Please wait one minute before trying again. <a href=\"/2024/day/3\">[Return]</a>",
+        );
+        assert_eq!(parse_reply(&high), Reply { outcome: Outcome::TooHigh, cooldown: 60 });
+
+        let low =
+            page("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.");
+        assert_eq!(parse_reply(&low), Reply { outcome: Outcome::TooLow, cooldown: 300 });
+
+        let wait = page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+             You have 1m 5s left to wait. <a href=\"/2024/day/3\">[Return to Day 3]</a>",
+        );
+        assert_eq!(parse_reply(&wait), Reply { outcome: Outcome::Wait, cooldown: 65 });
+
+        let solved = page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_reply(&solved).outcome, Outcome::AlreadySolved);
+
+        assert_eq!(parse_reply("<p>Maintenance</p>").outcome, Outcome::Unrecognized("Maintenance".to_string()));
+    }
+
+    #[test]
+    fn posts_the_answer_and_honours_the_cooldown() {
+        let (url, requests) = mock::serve(|request| {
+            let reply = if request.body.contains("answer=42") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer.  Please wait one minute before trying again."
+            };
+            (200, page(reply))
+        });
+        let cache_dir = temp_dir("cooldown");
+        let config =
+            Config { session: Some("secret".to_string()), base_url: url, year: None, cache_dir: cache_dir.clone() };
+
+        let reply = submit_answer(&config, 2024, 3, 1, "41", 1_000).unwrap();
+        assert_eq!(reply, Reply { outcome: Outcome::Incorrect, cooldown: 60 });
+
+        let err = submit_answer(&config, 2024, 3, 1, "42", 1_030).unwrap_err();
+        assert!(err.contains("30s"), "{}", err);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let reply = submit_answer(&config, 2024, 3, 2, "42", 1_060).unwrap();
+        assert_eq!(reply.outcome, Outcome::Correct);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].url, "/2024/day/3/answer");
+        assert_eq!(requests[1].cookie.as_deref(), Some("session=secret"));
+        assert_eq!(requests[1].body, "level=2&answer=42");
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
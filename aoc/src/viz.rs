@@ -0,0 +1,92 @@
+use aoc_common::frame::Frame;
+use aoc_common::{Params, ParseResult, Solution};
+use aoc_viz::Renderer;
+
+pub fn visualize_day<S: Solution>(input: &str, params: &Params, emit: &mut dyn FnMut(Frame)) -> ParseResult<()> {
+    let parsed = S::parse_with(input, params)?;
+    S::visualize(&parsed, emit);
+    Ok(())
+}
+
+/// Passes every `every`th frame a day emits to the renderer, and always its last
+/// one so animations end on the final state.
+pub struct Sampler {
+    renderer: Box<dyn Renderer>,
+    every: usize,
+    emitted: usize,
+    drawn: usize,
+    skipped: Option<Frame>,
+    error: Option<String>,
+}
+
+impl Sampler {
+    pub fn new(renderer: Box<dyn Renderer>, every: usize) -> Self {
+        Sampler { renderer, every: every.max(1), emitted: 0, drawn: 0, skipped: None, error: None }
+    }
+
+    fn draw(&mut self, frame: &Frame) {
+        match self.renderer.render(frame) {
+            Ok(()) => self.drawn += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+
+        if self.emitted.is_multiple_of(self.every) {
+            self.skipped = None;
+            self.draw(&frame);
+        } else {
+            self.skipped = Some(frame);
+        }
+        self.emitted += 1;
+    }
+
+    /// Draws the last frame if it was skipped and completes the output; returns
+    /// the number of frames drawn and emitted.
+    pub fn finish(mut self) -> Result<(usize, usize), String> {
+        if let Some(frame) = self.skipped.take() {
+            if self.error.is_none() {
+                self.draw(&frame);
+            }
+        }
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        self.renderer.finish()?;
+        Ok((self.drawn, self.emitted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::frame::{Cell, Color};
+    use aoc_common::grid::Grid;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<char>>>);
+
+    impl Renderer for Recorder {
+        fn render(&mut self, frame: &Frame) -> Result<(), String> {
+            self.0.lock().unwrap().push(frame.cells[(0, 0)].glyph);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn keeps_every_nth_frame_and_the_last() {
+        let glyphs = Arc::new(Mutex::new(Vec::new()));
+        let mut sampler = Sampler::new(Box::new(Recorder(Arc::clone(&glyphs))), 3);
+        for glyph in "abcdefgh".chars() {
+            sampler.push(Frame::new(Grid::new(1, 1, Cell::new(glyph, Color::WHITE))));
+        }
+
+        assert_eq!(sampler.finish(), Ok((4, 8)));
+        assert_eq!(*glyphs.lock().unwrap(), ['a', 'd', 'g', 'h']);
+    }
+}